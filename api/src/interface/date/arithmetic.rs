@@ -0,0 +1,305 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use super::{Date};
+
+use crate::types::{
+    error::{Error},
+    data::{
+        month_end::{MonthEnd},
+        duration::{Duration},
+        instant::{Instant},
+        zone::{Sign}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                constants::{
+                    week::{DAYS_IN_WEEK},
+                    seconds::{SECONDS_IN_HOUR, SECONDS_IN_MINUTE, NANOSECONDS_IN_SECOND},
+                },
+                functions::{
+                    days_from_presentation_date, days_in_month, validate_presentation_date
+                },
+                traits::{
+                    converter::{Converter}
+                }
+            }
+        },
+    },
+    counter::{
+        unix_time::{
+            constants::{
+                days::{UNIX_TIME_START_AFTER_DAY}
+            },
+        }
+    }
+};
+
+impl Date {
+    pub fn checked_add_days(&self, days: u128) -> Result<Date, Error> {
+        return self.with_era_days(self.era_days.checked_add(days).ok_or(Error::Overflow)?);
+    }
+
+    pub fn checked_sub_days(&self, days: u128) -> Result<Date, Error> {
+        return self.with_era_days(self.era_days.checked_sub(days).ok_or(Error::OutsideEra(self.view))?);
+    }
+
+    pub fn checked_add_weeks(&self, weeks: u128) -> Result<Date, Error> {
+        return self.checked_add_days(weeks.checked_mul(DAYS_IN_WEEK as u128).ok_or(Error::Overflow)?);
+    }
+
+    pub fn checked_sub_weeks(&self, weeks: u128) -> Result<Date, Error> {
+        return self.checked_sub_days(weeks.checked_mul(DAYS_IN_WEEK as u128).ok_or(Error::OutsideEra(self.view))?);
+    }
+
+    pub fn add_months(&self, months: u128, policy: MonthEnd) -> Result<Date, Error> {
+        let total_months: u128 = (self.year as u128 * self.view.system().months_in_year() as u128 + (self.month - 1_u8) as u128)
+            .checked_add(months)
+            .ok_or(Error::Overflow)?;
+
+        return self.with_months(total_months, policy);
+    }
+
+    pub fn sub_months(&self, months: u128, policy: MonthEnd) -> Result<Date, Error> {
+        let total_months: u128 = (self.year as u128 * self.view.system().months_in_year() as u128 + (self.month - 1_u8) as u128)
+            .checked_sub(months)
+            .ok_or(Error::OutsideEra(self.view))?;
+
+        return self.with_months(total_months, policy);
+    }
+
+    pub fn add_years(&self, years: u64, policy: MonthEnd) -> Result<Date, Error> {
+        return self.with_presentation_date(self.year.checked_add(years).ok_or(Error::Overflow)?, self.month, self.day, policy);
+    }
+
+    pub fn sub_years(&self, years: u64, policy: MonthEnd) -> Result<Date, Error> {
+        return self.with_presentation_date(self.year.checked_sub(years).ok_or(Error::OutsideEra(self.view))?, self.month, self.day, policy);
+    }
+
+    // Сначала годы и месяцы (политика применяется один раз), затем недели и дни, затем точное время.
+    pub fn checked_add_duration(&self, duration: Duration, policy: MonthEnd) -> Result<Date, Error> {
+        let months: u128 = (duration.years as u128)
+            .checked_mul(self.view.system().months_in_year() as u128)
+            .and_then(|months| months.checked_add(duration.months as u128))
+            .ok_or(Error::Overflow)?;
+
+        let days: u128 = (duration.weeks as u128 * DAYS_IN_WEEK as u128).checked_add(duration.days as u128).ok_or(Error::Overflow)?;
+
+        let nanoseconds: i128 = ((duration.hours as u128 * SECONDS_IN_HOUR + duration.minutes as u128 * SECONDS_IN_MINUTE + duration.seconds as u128) as i128)
+            .checked_mul(NANOSECONDS_IN_SECOND as i128)
+            .and_then(|nanoseconds| nanoseconds.checked_add(duration.nanoseconds as i128))
+            .ok_or(Error::Overflow)?;
+
+        let mut date: Date = *self;
+
+        if months > 0_u128 {
+            date = match duration.sign {
+                Sign::Unsigned => date.add_months(months, policy)?,
+                Sign::Signed => date.sub_months(months, policy)?
+            };
+        }
+
+        if days > 0_u128 {
+            date = match duration.sign {
+                Sign::Unsigned => date.checked_add_days(days)?,
+                Sign::Signed => date.checked_sub_days(days)?
+            };
+        }
+
+        if nanoseconds == 0_i128 {
+            return Ok(date);
+        }
+
+        let instant: Instant = date.instant();
+
+        let total: i128 = instant.seconds
+            .checked_mul(NANOSECONDS_IN_SECOND as i128)
+            .and_then(|total| total.checked_add(instant.nanoseconds as i128))
+            .and_then(|total| match duration.sign {
+                Sign::Unsigned => total.checked_add(nanoseconds),
+                Sign::Signed => total.checked_sub(nanoseconds)
+            })
+            .ok_or(Error::Overflow)?;
+
+        return Date::try_from(
+            self.view,
+            Instant::new(total.div_euclid(NANOSECONDS_IN_SECOND as i128), total.rem_euclid(NANOSECONDS_IN_SECOND as i128) as u32)?,
+            self.time_zone
+        );
+    }
+
+    pub fn checked_sub_duration(&self, duration: Duration, policy: MonthEnd) -> Result<Date, Error> {
+        let sign: Sign = match duration.sign {
+            Sign::Signed => Sign::Unsigned,
+            Sign::Unsigned => Sign::Signed
+        };
+
+        return self.checked_add_duration(Duration { sign: sign, ..duration }, policy);
+    }
+
+    fn with_months(&self, total_months: u128, policy: MonthEnd) -> Result<Date, Error> {
+        let months_in_year: u128 = self.view.system().months_in_year() as u128;
+
+        let year: u128 = total_months / months_in_year;
+
+        if year > u64::MAX as u128 {
+            return Err(Error::Overflow);
+        }
+
+        return self.with_presentation_date(year as u64, (total_months % months_in_year) as u8 + 1_u8, self.day, policy);
+    }
+
+    // Переносит дату на указанный день представления, сохраняя время внутри дня, часовой пояс и календарь.
+    pub(crate) fn with_presentation_date(&self, year: u64, month: u8, day: u8, policy: MonthEnd) -> Result<Date, Error> {
+        if year == 0_u64 {
+            return Err(Error::OutsideEra(self.view));
+        }
+
+        let last_day: u8 = days_in_month(self.view, year, month)?;
+
+        let (day, overflow_days): (u8, u8) = if day <= last_day {
+            (day, 0_u8)
+        } else {
+            match policy {
+                MonthEnd::Clamp => (last_day, 0_u8),
+                MonthEnd::RollOver => (last_day, day - last_day),
+                MonthEnd::Reject => return Err(Error::InvalidDay(day)),
+            }
+        };
+
+        validate_presentation_date(self.view, year, month, day)?;
+
+        let era_days: u128 = days_from_presentation_date(self.view, year, month, day) + overflow_days as u128;
+
+        return self.with_era_days(era_days - self.view.system().epoch_offset());
+    }
+
+    // Переносит дату на указанный день эры, сохраняя время внутри дня, часовой пояс и календарь.
+    pub(crate) fn with_era_days(&self, era_days: u128) -> Result<Date, Error> {
+        if era_days == 0_u128 {
+            return Err(Error::OutsideEra(self.view));
+        }
+
+        let mut date: Date = *self;
+
+        let shift: u128 = date.view.system().epoch_offset();
+
+        date.era_days = era_days.checked_add(shift).ok_or(Error::Overflow)?;
+
+        date.fill_time(UNIX_TIME_START_AFTER_DAY + shift)?;
+        date.fill_date(date.view)?;
+
+        date.era_days -= shift;
+
+        return Ok(date);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Date, Error, MonthEnd
+    };
+
+    use crate::types::{
+        data::{
+            instant::{Instant},
+            zone::{Sign, Zone}
+        },
+        planets::{
+            earth::{
+                calendar::{
+                    view::{CalendarView}
+                }
+            }
+        }
+    };
+
+    #[test]
+    fn test_add_days_between_views() {
+        let time_zone: Zone = Zone { sign: Sign::Unsigned, hours: 5_u8, minutes: 30_u8, seconds: 0_u8 };
+
+        for view in [CalendarView::Solar, CalendarView::Julian, CalendarView::Gregorian] {
+            let start: Date = Date::from(view, Instant::from_seconds(1_700_000_000_i128), time_zone);
+
+            for days in [0_u128, 1_u128, 29_u128, 365_u128, 1461_u128, 146097_u128] {
+                let later: Date = start.checked_add_days(days).unwrap();
+
+                assert_eq!(later, Date::from(view, Instant::from_seconds(start.unix_time() + days as i128 * 86400_i128), time_zone));
+                assert_eq!(later.checked_sub_days(days), Ok(start));
+            }
+
+            assert_eq!(start.checked_add_weeks(2), start.checked_add_days(14));
+            assert_eq!(start.checked_sub_days(start.era_days()), Err(Error::OutsideEra(view)));
+            assert_eq!(start.checked_add_days(u128::MAX), Err(Error::Overflow));
+        }
+    }
+
+    #[test]
+    fn test_add_months_end_of_month_policy() {
+        let time_zone: Zone = Zone { sign: Sign::Signed, hours: 3_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        let date: Date = Date::from(CalendarView::Gregorian, Instant::from_seconds(1_706_704_496_i128), time_zone);
+
+        let clamp: Date = date.add_months(1, MonthEnd::Clamp).unwrap();
+        assert_eq!((clamp.year(), clamp.month().index(), clamp.day()), (2024, 2, 29));
+
+        let roll_over: Date = date.add_months(1, MonthEnd::RollOver).unwrap();
+        assert_eq!((roll_over.year(), roll_over.month().index(), roll_over.day()), (2024, 3, 2));
+
+        assert_eq!(date.add_months(1, MonthEnd::Reject), Err(Error::InvalidDay(31)));
+
+        let next_year: Date = date.add_months(12, MonthEnd::Reject).unwrap();
+        assert_eq!((next_year.year(), next_year.month().index(), next_year.day()), (2025, 1, 31));
+
+        let previous: Date = date.sub_months(2, MonthEnd::Clamp).unwrap();
+        assert_eq!((previous.year(), previous.month().index(), previous.day()), (2023, 11, 30));
+        assert_eq!(date.sub_months(2, MonthEnd::Reject), Err(Error::InvalidDay(31)));
+
+        assert_eq!(clamp.time_zone(), time_zone);
+        assert_eq!(clamp.unix_time() % 86400, date.unix_time() % 86400);
+    }
+
+    #[test]
+    fn test_add_years_leap_day() {
+        let time_zone: Zone = Zone { sign: Sign::Unsigned, hours: 0_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        let julian: Date = Date::new(CalendarView::Julian, 1900, 2, 29, time_zone).unwrap();
+
+        let clamp: Date = julian.add_years(1, MonthEnd::Clamp).unwrap();
+        assert_eq!((clamp.year(), clamp.month().index(), clamp.day(), clamp.view()), (1901, 2, 28, CalendarView::Julian));
+
+        let roll_over: Date = julian.add_years(1, MonthEnd::RollOver).unwrap();
+        assert_eq!((roll_over.year(), roll_over.month().index(), roll_over.day()), (1901, 3, 1));
+
+        assert_eq!(julian.add_years(4, MonthEnd::Reject).map(|date| date.day()), Ok(29));
+        assert_eq!(julian.sub_years(1900, MonthEnd::Clamp), Err(Error::OutsideEra(CalendarView::Julian)));
+        assert_eq!(julian.add_years(u64::MAX, MonthEnd::Clamp), Err(Error::Overflow));
+    }
+}
@@ -0,0 +1,143 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use super::{Date};
+
+use crate::types::{
+    error::{Error},
+    data::{
+        zone::{Zone}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                easter::{Computus, Feast}
+            }
+        },
+    },
+};
+
+impl Date {
+    // Пасха года year по пасхалии computus (год в её календаре), в календаре view.
+    pub fn easter(computus: Computus, view: CalendarView, year: u64, time_zone: Zone) -> Result<Date, Error> {
+        let (month, day): (u8, u8) = computus.month_day(year);
+
+        return Date::new(computus.view(), year, month, day, time_zone)?.to_view(view);
+    }
+
+    pub fn feast(feast: Feast, computus: Computus, view: CalendarView, year: u64, time_zone: Zone) -> Result<Date, Error> {
+        let easter: Date = Date::easter(computus, view, year, time_zone)?;
+
+        let days: i16 = feast.days_from_easter();
+
+        if days < 0_i16 {
+            return easter.checked_sub_days(days.unsigned_abs() as u128);
+        }
+
+        return easter.checked_add_days(days as u128);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Computus, Date, Feast, Zone
+    };
+
+    use crate::types::{
+        planets::{
+            earth::{
+                calendar::{
+                    cutover::{Cutover},
+                    constants::{
+                        week::{Week}
+                    },
+                    traits::{
+                        day_of_week::{RataDie}
+                    }
+                }
+            }
+        }
+    };
+
+    fn day_of(date: Date) -> (u64, u8, u8) {
+        return (date.year(), date.month().index(), date.day());
+    }
+
+    #[test]
+    fn test_easter() {
+        for (year, western, orthodox) in [
+            (1818, (3, 22), (4, 26)),
+            (1943, (4, 25), (4, 25)),
+            (2000, (4, 23), (4, 30)),
+            (2010, (4, 4), (4, 4)),
+            (2019, (4, 21), (4, 28)),
+            (2021, (4, 4), (5, 2)),
+            (2024, (3, 31), (5, 5)),
+            (2025, (4, 20), (4, 20)),
+            (2038, (4, 25), (4, 25))
+        ] {
+            assert_eq!(day_of(Date::easter(Computus::Western, CalendarView::Gregorian, year, Zone::default()).unwrap()), (year, western.0, western.1));
+            assert_eq!(day_of(Date::easter(Computus::Orthodox, CalendarView::Gregorian, year, Zone::default()).unwrap()), (year, orthodox.0, orthodox.1));
+        }
+
+        // Православная Пасха 2024 года в юлианском календаре и западная Пасха до реформы в историческом календаре Рима.
+        assert_eq!(day_of(Date::easter(Computus::Orthodox, CalendarView::Julian, 2024, Zone::default()).unwrap()), (2024, 4, 22));
+        assert_eq!(day_of(Date::easter(Computus::Orthodox, CalendarView::Historical(Cutover::ROME), 1500, Zone::default()).unwrap()), (1500, 4, 19));
+        assert_eq!(day_of(Date::easter(Computus::Western, CalendarView::Historical(Cutover::ROME), 1583, Zone::default()).unwrap()), (1583, 4, 10));
+
+        // Пасха всегда воскресенье между 22 марта и 25 апреля в календаре своей пасхалии.
+        for computus in [Computus::Western, Computus::Orthodox] {
+            for year in (1_u64..=5_000_u64).chain([u64::MAX / 2_u64, u64::MAX - 1_u64]) {
+                let (month, day): (u8, u8) = computus.month_day(year);
+
+                assert!((month, day) >= (3, 22) && (month, day) <= (4, 25), "{computus:?} {year}");
+                assert_eq!(<Date as RataDie>::from(computus.view(), year, month, day), Week::Sunday, "{computus:?} {year}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_feast() {
+        for (feast, western, orthodox) in [
+            (Feast::AshWednesday, (2, 14), (3, 20)),
+            (Feast::PalmSunday, (3, 24), (4, 28)),
+            (Feast::GoodFriday, (3, 29), (5, 3)),
+            (Feast::Easter, (3, 31), (5, 5)),
+            (Feast::EasterMonday, (4, 1), (5, 6)),
+            (Feast::Ascension, (5, 9), (6, 13)),
+            (Feast::Pentecost, (5, 19), (6, 23)),
+            (Feast::WhitMonday, (5, 20), (6, 24)),
+            (Feast::CorpusChristi, (5, 30), (7, 4))
+        ] {
+            assert_eq!(day_of(Date::feast(feast, Computus::Western, CalendarView::Gregorian, 2024, Zone::default()).unwrap()), (2024, western.0, western.1), "{}", feast.name());
+            assert_eq!(day_of(Date::feast(feast, Computus::Orthodox, CalendarView::Gregorian, 2024, Zone::default()).unwrap()), (2024, orthodox.0, orthodox.1), "{}", feast.name());
+        }
+    }
+}
@@ -26,11 +26,19 @@
  * THE SOFTWARE.
  */
 
+mod arithmetic;
+mod period;
+mod easter;
+mod ordinal;
+mod week_day;
+mod week;
+
 pub use crate::types::data::date::{Date};
 
 use crate::types::{
+    error::{Error},
     data::{
-        time::{Time},
+        instant::{Instant},
         zone::{Sign, Zone}
     },
     planets::{
@@ -39,6 +47,9 @@ use crate::types::{
                 view::{CalendarView},
                 constants::{
                     months::{Months},
+                },
+                traits::{
+                    converter::{Julian, Gregorian, Solar, Converter}
                 }
             }
         },
@@ -58,20 +69,19 @@ use crate::{
  
 impl Date {
     pub fn utc(view: CalendarView) -> Date {
-        return Self::of(
+        return Self::from(
             view,
-            Time::unix().as_secs() as u128,
+            Instant::now(),
             Zone {
                 sign: Sign::Unsigned,
                 hours: 0, minutes: 0,
                 seconds: 0
-            },
-            true
+            }
         );
     }
 
     pub fn now(view: CalendarView, time_zone: Zone) -> Date {
-        return Self::of(view, Time::unix().as_secs() as u128, time_zone, false);
+        return Self::from(view, Instant::now(), time_zone);
     }
 
     #[cfg(any(
@@ -80,16 +90,123 @@ impl Date {
         feature = "platform_specific_functions_windows"
     ))]
     pub fn local(view: CalendarView) -> Date {
-        return Self::of(view, Time::unix().as_secs() as u128, local_timezone(), false);
+        return Self::from(view, Instant::now(), local_timezone());
+    }
+
+    pub fn from(view: CalendarView, instant: Instant, time_zone: Zone) -> Date {
+        match Self::try_from(view, instant, time_zone) {
+            Ok(date) => return date,
+            Err(error) => panic!("{}", error)
+        }
+    }
+
+    pub fn try_utc(view: CalendarView) -> Result<Date, Error> {
+        return Self::try_from(
+            view,
+            Instant::now(),
+            Zone {
+                sign: Sign::Unsigned,
+                hours: 0, minutes: 0,
+                seconds: 0
+            }
+        );
     }
 
-    pub fn from(view: CalendarView, unix_time: u128, time_zone: Zone, zone_in_unix: bool) -> Date {
-        return Self::of(view, unix_time, time_zone, zone_in_unix);
+    pub fn try_now(view: CalendarView, time_zone: Zone) -> Result<Date, Error> {
+        return Self::try_from(view, Instant::now(), time_zone);
+    }
+
+    #[cfg(any(
+        feature = "platform_specific_functions_darwin",
+        feature = "platform_specific_functions_unix",
+        feature = "platform_specific_functions_windows"
+    ))]
+    pub fn try_local(view: CalendarView) -> Result<Date, Error> {
+        return Self::try_from(view, Instant::now(), local_timezone());
+    }
+
+    pub fn try_from(view: CalendarView, instant: Instant, time_zone: Zone) -> Result<Date, Error> {
+        // Signed: unix time - time zone < type, Unsigned: unix time + time zone > type.
+        let local_seconds: i128 = instant.seconds.checked_add(time_zone.offset()).ok_or(Error::Overflow)?;
+
+        return Self::try_of(view, local_seconds, instant.nanoseconds, time_zone);
+    }
+
+    pub fn new(view: CalendarView, year: u64, month: u8, day: u8, time_zone: Zone) -> Result<Date, Error> {
+        let mut date: Date = Date {
+            day: day,
+            month: month,
+            year: year,
+            time_zone: time_zone,
+            unix_time: 0_i128,
+            nanoseconds: 0_u32,
+            era_days: 0_u128,
+            view: view
+        };
+
+        match view {
+            CalendarView::Julian => <Date as Julian>::try_to_presentation(&mut date)?,
+            CalendarView::Gregorian => <Date as Gregorian>::try_to_presentation(&mut date)?,
+            CalendarView::Solar => <Date as Solar>::try_to_presentation(&mut date)?,
+            CalendarView::Custom(_) | CalendarView::Historical(_) => <Date as Converter>::convert_presentation(&mut date, view)?,
+        }
+
+        return Ok(date);
+    }
+
+    pub fn to_view(self, view: CalendarView) -> Result<Date, Error> {
+        let mut date: Date = self;
+
+        match view {
+            CalendarView::Julian => <Date as Julian>::try_to_date(&mut date)?,
+            CalendarView::Gregorian => <Date as Gregorian>::try_to_date(&mut date)?,
+            CalendarView::Solar => <Date as Solar>::try_to_date(&mut date)?,
+            CalendarView::Custom(_) | CalendarView::Historical(_) => <Date as Converter>::convert_date(&mut date, view)?,
+        }
+
+        return Ok(date);
+    }
+
+    pub fn same_civil_fields(&self, other: &Date) -> bool {
+        return (self.day, self.month, self.year, self.time_zone, self.unix_time, self.nanoseconds, self.era_days, self.view)
+            == (other.day, other.month, other.year, other.time_zone, other.unix_time, other.nanoseconds, other.era_days, other.view);
+    }
+
+    pub const fn day(&self) -> u8 {
+        return self.day;
     }
 
     pub fn month(&self) -> Months {
         return Months::from(self.month);
     }
+
+    pub const fn year(&self) -> u64 {
+        return self.year;
+    }
+
+    pub const fn time_zone(&self) -> Zone {
+        return self.time_zone;
+    }
+
+    pub const fn unix_time(&self) -> i128 {
+        return self.unix_time - self.time_zone.offset();
+    }
+
+    pub const fn instant(&self) -> Instant {
+        return Instant { seconds: self.unix_time(), nanoseconds: self.nanoseconds };
+    }
+
+    pub const fn nanoseconds(&self) -> u32 {
+        return self.nanoseconds;
+    }
+
+    pub const fn era_days(&self) -> u128 {
+        return self.era_days;
+    }
+
+    pub const fn view(&self) -> CalendarView {
+        return self.view;
+    }
 }
 
 #[cfg(test)]
@@ -97,10 +214,15 @@ mod tests {
 
     use super::{
         CalendarView,
-        Date, Sign, Zone,
+        Date, Instant, Sign, Zone, Error,
+        Julian, Gregorian, Solar,
         local_timezone
     };
 
+    use std::{
+        collections::{BTreeSet, HashSet}
+    };
+
     use libc::{
         time_t, tm,
         gmtime_r, localtime_r
@@ -108,6 +230,9 @@ mod tests {
 
     use crate::{
         types::{
+            data::{
+                time::{Time}
+            },
             planets::{
                 earth::{
                     calendar::{
@@ -126,16 +251,16 @@ mod tests {
 
         let gmt_time_zone: Zone = Zone { sign: Sign::Unsigned, hours: 0_u8, minutes: 0_u8, seconds: 0_u8 };
 
-        let current_seconds: u64 = Date::now(CalendarView::Gregorian, gmt_time_zone).unix_time as u64;
+        let current_seconds: u64 = Date::now(CalendarView::Gregorian, gmt_time_zone).unix_time() as u64;
 
         for unix_time in (0..=current_seconds).step_by(SECONDS_IN_DAY as usize) {
             let time_c: time_t = unix_time as time_t;
 
-            if unsafe { gmtime_r(&time_c, &mut date_struct_libc) } == std::ptr::null_mut() {
+            if unsafe { gmtime_r(&time_c, &mut date_struct_libc) }.is_null() {
                 panic!("[ERROR]: Pointer is NULL (gmtime_r)!")
             }
 
-            let date: Date = Date::from(CalendarView::Gregorian, unix_time as u128, gmt_time_zone, false);
+            let date: Date = Date::from(CalendarView::Gregorian, Instant::from_seconds(unix_time as i128), gmt_time_zone);
 
             assert_eq!(
                 (
@@ -165,18 +290,18 @@ mod tests {
 
         let mut date_struct_libc: tm = unsafe { std::mem::zeroed::<tm>() };
 
-        let current_seconds: u64 = Date::now(CalendarView::Gregorian, local_timezone()).unix_time as u64;
+        let current_seconds: u64 = Date::now(CalendarView::Gregorian, local_timezone()).unix_time() as u64;
 
         for unix_time in (0..=current_seconds).step_by(SECONDS_IN_DAY as usize) {
             let time_c: time_t = unix_time as time_t;
 
-            if unsafe { localtime_r(&time_c, &mut date_struct_libc) } == std::ptr::null_mut() {
+            if unsafe { localtime_r(&time_c, &mut date_struct_libc) }.is_null() {
                 panic!("[ERROR]: Pointer is NULL (localtime_r)!")
             }
 
             if date_struct_libc.tm_gmtoff < 0 { sign = Sign::Signed } else { sign = Sign::Unsigned };
 
-            date = Date::from(CalendarView::Gregorian, unix_time as u128, Zone::from_seconds(sign, date_struct_libc.tm_gmtoff.unsigned_abs() as u32), false);
+            date = Date::from(CalendarView::Gregorian, Instant::from_seconds(unix_time as i128), Zone::from_seconds(sign, date_struct_libc.tm_gmtoff.unsigned_abs() as u32));
 
             assert_eq!(
                 (
@@ -194,4 +319,156 @@ mod tests {
             )
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_try_date_from_overflow() {
+        let (west_zone, east_zone): (Zone, Zone)
+        =
+        (
+            Zone { sign: Sign::Signed, hours: 3_u8, minutes: 0_u8, seconds: 0_u8 },
+            Zone { sign: Sign::Unsigned, hours: 3_u8, minutes: 0_u8, seconds: 0_u8 }
+        );
+
+        for view in [CalendarView::Solar, CalendarView::Julian, CalendarView::Gregorian] {
+            assert_eq!(Date::try_from(view, Instant::from_seconds(i128::MIN), west_zone), Err(Error::Overflow));
+            assert_eq!(Date::try_from(view, Instant::from_seconds(i128::MAX), east_zone), Err(Error::Overflow));
+            assert_eq!(Date::try_from(view, Instant::from_seconds(i128::MAX - 10800), east_zone), Err(Error::Overflow));
+            assert_eq!(Date::try_from(view, Instant::from_seconds(i128::MIN), east_zone), Err(Error::OutsideEra(view)));
+
+            let date: Date = Date::from(view, Instant::from_seconds(0_i128), west_zone);
+
+            assert_eq!((date.unix_time, date.unix_time(), date.time_zone()), (-10800_i128, 0_i128, west_zone));
+            assert_eq!(Date::from(view, date.instant(), east_zone).unix_time, 10800_i128);
+        }
+
+        // 0001-01-01 (Gregorian) is the first day of the era.
+        assert_eq!(Date::try_from(CalendarView::Gregorian, Instant::from_seconds(-62135596800_i128 - 10800), east_zone).map(|date| date.era_days()), Ok(1));
+        assert_eq!(Date::try_from(CalendarView::Gregorian, Instant::from_seconds(-62135596801_i128 - 10800), east_zone), Err(Error::OutsideEra(CalendarView::Gregorian)));
+    }
+
+    #[test]
+    fn test_pre_epoch_round_trip() {
+        let time_zone: Zone = Zone { sign: Sign::Signed, hours: 5_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        let mut date: Date = Date::new(CalendarView::Julian, 800, 12, 25, time_zone).unwrap();
+
+        let (unix_time, era_days): (i128, u128) = (date.unix_time(), date.era_days());
+
+        assert!(unix_time < 0_i128);
+        assert_eq!(unix_time, -36890294400_i128 + 5 * 3600);
+        assert_eq!(Date::from(CalendarView::Julian, date.instant(), time_zone), date);
+
+        <Date as Gregorian>::to_date(&mut date);
+        assert_eq!((date.year(), date.month().index(), date.day(), date.unix_time(), date.era_days()), (800, 12, 29, unix_time, era_days));
+
+        <Date as Solar>::to_date(&mut date);
+        assert_eq!((date.year(), date.month().index(), date.day(), date.unix_time(), date.era_days()), (800, 12, 30, unix_time, era_days));
+
+        <Date as Julian>::to_date(&mut date);
+        assert_eq!((date.year(), date.month().index(), date.day(), date.unix_time(), date.era_days()), (800, 12, 25, unix_time, era_days));
+
+        let time: Time = Time::from(Instant::from_seconds(unix_time - 1), time_zone);
+        assert_eq!((time.hours, time.minutes, time.seconds, time.unix_time), (23, 59, 59, unix_time - 1));
+    }
+
+    #[test]
+    fn test_new_date_validation() {
+        let gmt_time_zone: Zone = Zone { sign: Sign::Unsigned, hours: 0_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        for view in [CalendarView::Solar, CalendarView::Julian, CalendarView::Gregorian] {
+            let date: Date = Date::new(view, 2024, 3, 1, gmt_time_zone).unwrap();
+
+            assert_eq!(date, Date::from(view, date.instant(), gmt_time_zone));
+            assert_eq!((date.year(), date.month().index(), date.day(), date.view()), (2024, 3, 1, view));
+
+            assert_eq!(Date::new(view, 0, 3, 1, gmt_time_zone), Err(Error::InvalidYear(0)));
+            assert_eq!(Date::new(view, 2024, 13, 1, gmt_time_zone), Err(Error::InvalidMonth(13)));
+            assert_eq!(Date::new(view, 2023, 2, 29, gmt_time_zone), Err(Error::InvalidDay(29)));
+            assert_eq!(Date::new(view, 2024, 4, 31, gmt_time_zone), Err(Error::InvalidDay(31)));
+        }
+
+        assert_eq!(Date::new(CalendarView::Gregorian, 2024, 2, 29, gmt_time_zone).map(|date| date.unix_time()), Ok(1709164800));
+        assert_eq!(Date::new(CalendarView::Julian, 1, 1, 2, gmt_time_zone), Err(Error::OutsideEra(CalendarView::Julian)));
+        assert_eq!(Date::new(CalendarView::Julian, 1, 1, 3, gmt_time_zone).map(|date| date.era_days()), Ok(1));
+    }
+
+    #[test]
+    fn test_nanoseconds_preserved() {
+        let time_zone: Zone = Zone { sign: Sign::Signed, hours: 7_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        let mut date: Date = Date::from(CalendarView::Gregorian, Instant::new(-1_i128, 999_999_999_u32).unwrap(), time_zone);
+
+        assert_eq!((date.year(), date.month().index(), date.day(), date.unix_time(), date.nanoseconds()), (1969, 12, 31, -1, 999_999_999));
+
+        <Date as Julian>::to_date(&mut date);
+        <Date as Solar>::to_date(&mut date);
+        assert_eq!(date.nanoseconds(), 999_999_999);
+        assert_eq!(date.instant(), Instant::new(-1_i128, 999_999_999_u32).unwrap());
+
+        assert_eq!(date.checked_add_days(1).map(|date| date.nanoseconds()), Ok(999_999_999));
+        assert_eq!(Date::from(CalendarView::Gregorian, Instant::from_seconds(0_i128), time_zone).nanoseconds(), 0);
+    }
+
+    #[test]
+    fn test_to_view() {
+        let time_zone: Zone = Zone { sign: Sign::Unsigned, hours: 3_u8, minutes: 30_u8, seconds: 0_u8 };
+
+        let date: Date = Date::from(CalendarView::Julian, Instant::new(-36890294400_i128 + 45_296, 500_u32).unwrap(), time_zone);
+
+        let (gregorian, solar): (Date, Date) = (date.to_view(CalendarView::Gregorian).unwrap(), date.to_view(CalendarView::Solar).unwrap());
+
+        assert_eq!((gregorian.year(), gregorian.month().index(), gregorian.day(), gregorian.view()), (800, 12, 29, CalendarView::Gregorian));
+        assert_eq!((solar.year(), solar.month().index(), solar.day(), solar.view()), (800, 12, 30, CalendarView::Solar));
+
+        for converted in [gregorian, solar, date.to_view(CalendarView::Julian).unwrap()] {
+            assert_eq!((converted.instant(), converted.time_zone(), converted.era_days()), (date.instant(), time_zone, date.era_days()));
+            assert_eq!(converted.to_view(CalendarView::Julian), Ok(date));
+        }
+
+        let missing: Date = Date {
+            day: 1_u8, month: 1_u8, year: 1_u64,
+            time_zone: time_zone,
+            unix_time: 0_i128,
+            nanoseconds: 0_u32,
+            era_days: 0_u128,
+            view: CalendarView::Julian
+        };
+
+        assert_eq!(missing.to_view(CalendarView::Gregorian), Err(Error::OutsideEra(CalendarView::Julian)));
+        assert_eq!(missing.to_view(CalendarView::Solar), Err(Error::OutsideEra(CalendarView::Julian)));
+    }
+
+    #[test]
+    fn test_instant_ordering() {
+        let (west, east): (Zone, Zone)
+        =
+        (
+            Zone { sign: Sign::Signed, hours: 5_u8, minutes: 0_u8, seconds: 0_u8 },
+            Zone { sign: Sign::Unsigned, hours: 3_u8, minutes: 0_u8, seconds: 0_u8 }
+        );
+
+        let (earlier, later): (Date, Date)
+        =
+        (
+            Date::new(CalendarView::Gregorian, 2023, 4, 1, east).unwrap(),
+            Date::new(CalendarView::Gregorian, 2024, 3, 2, east).unwrap()
+        );
+
+        assert!(earlier < later);
+
+        let julian: Date = later.to_view(CalendarView::Julian).unwrap();
+        let west_later: Date = Date::from(CalendarView::Solar, later.instant(), west);
+
+        assert_eq!(julian, later);
+        assert_eq!(west_later, later);
+        assert!(!julian.same_civil_fields(&later));
+        assert!(!west_later.same_civil_fields(&later));
+        assert!(later.to_view(CalendarView::Gregorian).unwrap().same_civil_fields(&later));
+
+        let unique: BTreeSet<Date> = [later, earlier, julian, west_later].into_iter().collect();
+        assert_eq!(unique.into_iter().collect::<Vec<Date>>(), vec![earlier, later]);
+
+        let hashed: HashSet<Date> = [later, julian, west_later].into_iter().collect();
+        assert_eq!(hashed.len(), 1);
+    }
+}
@@ -0,0 +1,264 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use super::{Date};
+
+use crate::types::{
+    error::{Error},
+    data::{
+        zone::{Zone}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                constants::{
+                    week::{Week, DAYS_IN_WEEK},
+                    days::{BASE_MONTH_SUM_DAYS, LEAP_MONTH_SUM_DAYS}
+                },
+                functions::{
+                    days_from_presentation_date, days_in_month, is_leap_year, year_days
+                },
+                traits::{
+                    day_of_week::{RataDie}
+                }
+            }
+        },
+    },
+};
+
+const QUARTERS_IN_YEAR: u8 = 4_u8;
+
+// Суммы дней по месяцам для календарей из 12 месяцев, у остальных месяцы и пропуски дней считает система календаря.
+fn month_sum_days(view: CalendarView, year: u64) -> Option<&'static [u16]> {
+    return match view {
+        CalendarView::Julian | CalendarView::Gregorian | CalendarView::Solar => {
+            if !is_leap_year(view, year) {
+                Some(&BASE_MONTH_SUM_DAYS)
+            } else {
+                Some(&LEAP_MONTH_SUM_DAYS)
+            }
+        },
+        CalendarView::Custom(_) | CalendarView::Historical(_) => None
+    };
+}
+
+impl Date {
+    // Порядковый номер дня в году, начиная с 1.
+    pub fn ordinal(&self) -> u16 {
+        if let Some(sum_days) = month_sum_days(self.view, self.year) {
+            if self.month == 1_u8 {
+                return self.day as u16;
+            }
+
+            return sum_days[(self.month - 2_u8) as usize] + self.day as u16;
+        }
+
+        return (days_from_presentation_date(self.view, self.year, self.month, self.day) - days_from_presentation_date(self.view, self.year, 1_u8, 1_u8) + 1_u128) as u16;
+    }
+
+    pub fn from_ordinal(view: CalendarView, year: u64, ordinal: u16, time_zone: Zone) -> Result<Date, Error> {
+        let first: Date = Date::new(view, year, 1_u8, 1_u8, time_zone)?;
+
+        if ordinal == 0_u16 || ordinal as u128 > year_days(view, year) {
+            return Err(Error::InvalidYearDay(ordinal));
+        }
+
+        if let Some(sum_days) = month_sum_days(view, year) {
+            let month: usize = sum_days.iter().position(|&sum| ordinal <= sum).ok_or(Error::InvalidYearDay(ordinal))?;
+
+            if month == 0_usize {
+                return Date::new(view, year, 1_u8, ordinal as u8, time_zone);
+            }
+
+            return Date::new(view, year, month as u8 + 1_u8, (ordinal - sum_days[month - 1_usize]) as u8, time_zone);
+        }
+
+        return first.with_era_days(first.era_days + ordinal as u128 - 1_u128);
+    }
+
+    // Квартал 1 - 4, в календаре не из 12 месяцев последний квартал забирает остаток месяцев.
+    pub fn quarter(&self) -> u8 {
+        let months: u8 = self.view.system().months_in_year();
+
+        return ((self.month as u16 * QUARTERS_IN_YEAR as u16 + months as u16 - 1_u16) / months as u16) as u8;
+    }
+
+    pub fn quarter_start(&self) -> Result<Date, Error> {
+        let months: u16 = self.view.system().months_in_year() as u16;
+
+        let month: u8 = ((self.quarter() - 1_u8) as u16 * months / QUARTERS_IN_YEAR as u16) as u8 + 1_u8;
+
+        return Date::new(self.view, self.year, month, 1_u8, self.time_zone);
+    }
+
+    pub fn quarter_end(&self) -> Result<Date, Error> {
+        let months: u16 = self.view.system().months_in_year() as u16;
+
+        let month: u8 = (self.quarter() as u16 * months / QUARTERS_IN_YEAR as u16) as u8;
+
+        return Date::new(self.view, self.year, month, days_in_month(self.view, self.year, month)?, self.time_zone);
+    }
+
+    // Строка месяца в календаре, недели которого начинаются с week_start: первая неделя содержит 1 число.
+    pub fn week_of_month(&self, week_start: Week) -> u8 {
+        let first_day: Week = <Date as RataDie>::from(self.view, self.year, self.month, 1_u8);
+
+        let shift: u8 = (first_day.index() + DAYS_IN_WEEK - week_start.index()) % DAYS_IN_WEEK;
+
+        // Дни от начала месяца, у месяца перехода исторического календаря без пропущенных дней.
+        let month_day: u8 = (days_from_presentation_date(self.view, self.year, self.month, self.day) - days_from_presentation_date(self.view, self.year, self.month, 1_u8)) as u8;
+
+        return (month_day + shift) / DAYS_IN_WEEK + 1_u8;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Date, Error, Week, Zone
+    };
+
+    use crate::types::{
+        planets::{
+            earth::{
+                calendar::{
+                    cutover::{Cutover},
+                    system::{CalendarSystem},
+                    functions::{year_days}
+                }
+            }
+        }
+    };
+
+    // Десять месяцев по 31 и 30 дней, високосный день в конце года.
+    #[derive(Debug)]
+    struct TenMonths;
+
+    impl CalendarSystem for TenMonths {
+        fn name(&self) -> &'static str {
+            return "Ten Months";
+        }
+
+        fn is_leap_year(&self, year: u64) -> bool {
+            return year % 4_u64 == 0_u64;
+        }
+
+        fn sum_leap_years(&self, year: u64) -> u64 {
+            return year / 4_u64;
+        }
+
+        fn months_in_year(&self) -> u8 {
+            return 10_u8;
+        }
+
+        fn month_days(&self, month: u8, leap: bool) -> u8 {
+            return 31_u8 - (month % 2_u8 == 0_u8 && !(leap && month == 10_u8)) as u8;
+        }
+
+        fn week_day_shift(&self) -> u8 {
+            return 0_u8;
+        }
+    }
+
+    const TEN_MONTHS: CalendarView = CalendarView::Custom(&TenMonths);
+
+    const ROME: CalendarView = CalendarView::Historical(Cutover::ROME);
+
+    #[test]
+    fn test_ordinal() {
+        for ((view, year, month, day), ordinal) in [
+            ((CalendarView::Gregorian, 2024, 3, 1), 61),
+            ((CalendarView::Gregorian, 2023, 12, 31), 365),
+            ((CalendarView::Julian, 1900, 12, 31), 366),
+            ((CalendarView::Solar, 2024, 1, 9), 9),
+            ((ROME, 1582, 10, 15), 278),
+            ((ROME, 1582, 12, 31), 355),
+            ((TEN_MONTHS, 2024, 10, 31), 306)
+        ] {
+            let date: Date = Date::new(view, year, month, day, Zone::default()).unwrap();
+
+            assert_eq!(date.ordinal(), ordinal, "{view:?} {year}-{month}-{day}");
+            assert!(Date::from_ordinal(view, year, ordinal, Zone::default()).unwrap().same_civil_fields(&date));
+        }
+
+        for view in [CalendarView::Gregorian, CalendarView::Julian, CalendarView::Solar, ROME, TEN_MONTHS] {
+            for year in [1581, 1582, 1900, 2000, 2023, 2024] {
+                let mut date: Date = Date::new(view, year, 1, 1, Zone::default()).unwrap();
+
+                for ordinal in 1..=year_days(view, year) as u16 {
+                    assert_eq!(date.ordinal(), ordinal);
+                    assert!(Date::from_ordinal(view, year, ordinal, Zone::default()).unwrap().same_civil_fields(&date));
+
+                    date = date.checked_add_days(1).unwrap();
+                }
+
+                assert_eq!((date.year(), date.ordinal()), (year + 1, 1));
+                assert_eq!(Date::from_ordinal(view, year, year_days(view, year) as u16 + 1, Zone::default()), Err(Error::InvalidYearDay(year_days(view, year) as u16 + 1)));
+            }
+        }
+
+        assert_eq!(Date::from_ordinal(CalendarView::Gregorian, 2024, 0, Zone::default()), Err(Error::InvalidYearDay(0)));
+        assert_eq!(Date::from_ordinal(CalendarView::Gregorian, 0, 1, Zone::default()), Err(Error::InvalidYear(0)));
+    }
+
+    #[test]
+    fn test_quarter() {
+        for (view, quarters) in [
+            (CalendarView::Gregorian, [(1, 3), (4, 6), (7, 9), (10, 12)]),
+            (TEN_MONTHS, [(1, 2), (3, 5), (6, 7), (8, 10)])
+        ] {
+            for (quarter, (first, last)) in (1_u8..).zip(quarters) {
+                for month in first..=last {
+                    let date: Date = Date::new(view, 2024, month, 15, Zone::default()).unwrap();
+
+                    let (start, end): (Date, Date) = (date.quarter_start().unwrap(), date.quarter_end().unwrap());
+
+                    assert_eq!(date.quarter(), quarter);
+                    assert_eq!((start.month().index(), start.day()), (first, 1));
+                    assert_eq!((end.month().index(), end.day()), (last, view.system().month_days(last, true)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_week_of_month() {
+        // Март 2024 года начинается с пятницы.
+        for (day, monday, sunday) in [(1, 1, 1), (2, 1, 1), (3, 1, 2), (4, 2, 2), (10, 2, 3), (11, 3, 3), (30, 5, 5), (31, 5, 6)] {
+            let date: Date = Date::new(CalendarView::Gregorian, 2024, 3, day, Zone::default()).unwrap();
+
+            assert_eq!((date.week_of_month(Week::Monday), date.week_of_month(Week::Sunday)), (monday, sunday), "{day}");
+        }
+
+        // Октябрь 1582 года: за четвергом 4 сразу идёт пятница 15.
+        for (day, week) in [(4, 1), (15, 1), (17, 1), (18, 2), (31, 3)] {
+            assert_eq!(Date::new(ROME, 1582, 10, day, Zone::default()).unwrap().week_of_month(Week::Monday), week, "{day}");
+        }
+    }
+}
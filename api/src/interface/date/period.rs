@@ -0,0 +1,182 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use super::{Date};
+
+use crate::types::{
+    error::{Error},
+    data::{
+        zone::{Sign},
+        period::{Period},
+        month_end::{MonthEnd}
+    }
+};
+
+impl Date {
+    pub fn days_until(&self, other: &Date) -> i128 {
+        if other.era_days >= self.era_days {
+            return (other.era_days - self.era_days) as i128;
+        } else {
+            return -((self.era_days - other.era_days) as i128);
+        }
+    }
+
+    pub fn period_until(&self, other: &Date) -> Result<Period, Error> {
+        // Период считается в календаре self, даты в другом календаре приводятся к нему через дни эры.
+        let (sign, start, end): (Sign, Date, Date) = if other.era_days >= self.era_days {
+            (Sign::Unsigned, *self, self.with_era_days(other.era_days)?)
+        } else {
+            (Sign::Signed, self.with_era_days(other.era_days)?, *self)
+        };
+
+        let months_in_year: u128 = self.view.system().months_in_year() as u128;
+
+        let mut months: u128 = (end.year as u128 * months_in_year + end.month as u128)
+                             - (start.year as u128 * months_in_year + start.month as u128);
+
+        if months > 0_u128 && end.day < start.day {
+            months -= 1_u128;
+        }
+
+        let days: u128 = end.era_days.checked_sub(start.add_months(months, MonthEnd::Clamp)?.era_days).ok_or(Error::Overflow)?;
+
+        return Ok(Period {
+            sign: sign,
+            years: (months / months_in_year) as u64,
+            months: (months % months_in_year) as u8,
+            days: days as u8
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Date, Period, Sign
+    };
+
+    use crate::types::{
+        data::{
+            zone::{Zone}
+        },
+        planets::{
+            earth::{
+                calendar::{
+                    view::{CalendarView},
+                    system::{CalendarSystem}
+                }
+            }
+        }
+    };
+
+    // Десять месяцев по 31 и 30 дней, високосный день в конце года.
+    #[derive(Debug)]
+    struct TenMonths;
+
+    impl CalendarSystem for TenMonths {
+        fn name(&self) -> &'static str {
+            return "Ten Months";
+        }
+
+        fn is_leap_year(&self, year: u64) -> bool {
+            return year % 4_u64 == 0_u64;
+        }
+
+        fn sum_leap_years(&self, year: u64) -> u64 {
+            return year / 4_u64;
+        }
+
+        fn months_in_year(&self) -> u8 {
+            return 10_u8;
+        }
+
+        fn month_days(&self, month: u8, leap: bool) -> u8 {
+            return 31_u8 - (month % 2_u8 == 0_u8 && !(leap && month == 10_u8)) as u8;
+        }
+
+        fn week_day_shift(&self) -> u8 {
+            return 0_u8;
+        }
+    }
+
+    const TEN_MONTHS: CalendarView = CalendarView::Custom(&TenMonths);
+
+    #[test]
+    fn test_days_until() {
+        let time_zone: Zone = Zone::default();
+
+        let (start, end): (Date, Date)
+        =
+        (
+            Date::new(CalendarView::Gregorian, 2024, 1, 1, time_zone).unwrap(),
+            Date::new(CalendarView::Gregorian, 2025, 1, 1, time_zone).unwrap()
+        );
+
+        assert_eq!(start.days_until(&end), 366);
+        assert_eq!(end.days_until(&start), -366);
+
+        let julian: Date = Date::new(CalendarView::Julian, 2023, 12, 19, time_zone).unwrap();
+
+        assert_eq!(julian.days_until(&start), 0);
+    }
+
+    #[test]
+    fn test_period_until() {
+        let time_zone: Zone = Zone::default();
+
+        let period = |view: CalendarView, from: (u64, u8, u8), to: (u64, u8, u8)| -> Period {
+            return Date::new(view, from.0, from.1, from.2, time_zone).unwrap()
+                .period_until(&Date::new(view, to.0, to.1, to.2, time_zone).unwrap()).unwrap();
+        };
+
+        assert_eq!(period(CalendarView::Gregorian, (2024, 1, 31), (2024, 3, 1)), Period { sign: Sign::Unsigned, years: 0, months: 1, days: 1 });
+        assert_eq!(period(CalendarView::Gregorian, (2020, 2, 29), (2024, 2, 28)), Period { sign: Sign::Unsigned, years: 3, months: 11, days: 30 });
+        assert_eq!(period(CalendarView::Gregorian, (2024, 3, 15), (2023, 1, 10)), Period { sign: Sign::Signed, years: 1, months: 2, days: 5 });
+        assert_eq!(period(CalendarView::Julian, (1900, 2, 1), (1900, 3, 1)), Period { sign: Sign::Unsigned, years: 0, months: 1, days: 0 });
+        assert_eq!(period(CalendarView::Solar, (2000, 5, 5), (2000, 5, 5)), Period::default());
+        assert_eq!(period(TEN_MONTHS, (2024, 3, 5), (2025, 2, 10)), Period { sign: Sign::Unsigned, years: 0, months: 9, days: 5 });
+        assert_eq!(period(TEN_MONTHS, (2023, 1, 31), (2025, 3, 1)), Period { sign: Sign::Unsigned, years: 2, months: 1, days: 1 });
+        assert_eq!(period(TEN_MONTHS, (2025, 2, 10), (2024, 3, 5)), Period { sign: Sign::Signed, years: 0, months: 9, days: 5 });
+    }
+
+    #[test]
+    fn test_period_until_between_views() {
+        let time_zone: Zone = Zone::default();
+
+        let (birth, today): (Date, Date)
+        =
+        (
+            Date::new(CalendarView::Julian, 1900, 2, 28, time_zone).unwrap(),
+            Date::new(CalendarView::Gregorian, 2024, 3, 13, time_zone).unwrap()
+        );
+
+        // 2024/03/13 Gregorian is 2024/02/29 Julian.
+        assert_eq!(birth.period_until(&today), Ok(Period { sign: Sign::Unsigned, years: 124, months: 0, days: 1 }));
+        assert_eq!(today.period_until(&birth), Ok(Period { sign: Sign::Signed, years: 124, months: 0, days: 1 }));
+    }
+}
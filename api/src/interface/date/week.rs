@@ -0,0 +1,240 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use super::{Date};
+
+use crate::types::{
+    error::{Error},
+    data::{
+        zone::{Zone}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                constants::{
+                    week::{Week, DAYS_IN_WEEK}
+                },
+                functions::{
+                    days_from_presentation_date, year_days,
+                    WeekRule, ISO_WEEK, US_WEEK
+                },
+                traits::{
+                    day_of_week::{RataDie}
+                }
+            }
+        },
+    },
+};
+
+impl Date {
+    // Недельный год, номер недели (1 - 53) и день недели по ISO 8601 в календаре даты.
+    pub fn iso_week(&self) -> Result<(u64, u8, Week), Error> {
+        return self.week_of(ISO_WEEK);
+    }
+
+    pub fn from_iso_week(view: CalendarView, year: u64, week: u8, week_day: Week, time_zone: Zone) -> Result<Date, Error> {
+        return Self::from_week_of(ISO_WEEK, view, year, week, week_day, time_zone);
+    }
+
+    // Недели с воскресенья, первая неделя года содержит 1 января.
+    pub fn us_week(&self) -> Result<(u64, u8, Week), Error> {
+        return self.week_of(US_WEEK);
+    }
+
+    pub fn from_us_week(view: CalendarView, year: u64, week: u8, week_day: Week, time_zone: Zone) -> Result<Date, Error> {
+        return Self::from_week_of(US_WEEK, view, year, week, week_day, time_zone);
+    }
+
+    fn week_of(&self, rule: WeekRule) -> Result<(u64, u8, Week), Error> {
+        let week_day: Week = <Date as RataDie>::week_day(self);
+
+        let year_day: i128 = (days_from_presentation_date(self.view, self.year, self.month, self.day) - days_from_presentation_date(self.view, self.year, 1_u8, 1_u8)) as i128;
+
+        let from_first_week: i128 = year_day - rule.first_week_offset(self.view, self.year);
+
+        if from_first_week < 0_i128 {
+            // День относится к последней неделе прошлого года.
+            if self.year == 1_u64 {
+                return Err(Error::OutsideEra(self.view));
+            }
+
+            let previous_year: u64 = self.year - 1_u64;
+
+            let from_previous: i128 = from_first_week + year_days(self.view, previous_year) as i128 - rule.first_week_offset(self.view, previous_year);
+
+            return Ok((previous_year, (from_previous / DAYS_IN_WEEK as i128) as u8 + 1_u8, week_day));
+        }
+
+        let week: u8 = (from_first_week / DAYS_IN_WEEK as i128) as u8 + 1_u8;
+
+        if week > rule.weeks_in_year(self.view, self.year) {
+            return Ok((self.year.checked_add(1_u64).ok_or(Error::Overflow)?, 1_u8, week_day));
+        }
+
+        return Ok((self.year, week, week_day));
+    }
+
+    fn from_week_of(rule: WeekRule, view: CalendarView, year: u64, week: u8, week_day: Week, time_zone: Zone) -> Result<Date, Error> {
+        let first: Date = Date::new(view, year, 1_u8, 1_u8, time_zone)?;
+
+        if week == 0_u8 || week > rule.weeks_in_year(view, year) {
+            return Err(Error::InvalidWeek(week));
+        }
+
+        let days: i128 = rule.first_week_offset(view, year) + (week - 1_u8) as i128 * DAYS_IN_WEEK as i128 + rule.position(&week_day) as i128;
+
+        if days < 0_i128 {
+            return first.with_era_days(first.era_days.checked_sub(days.unsigned_abs()).ok_or(Error::OutsideEra(view))?);
+        }
+
+        return first.with_era_days(first.era_days.checked_add(days as u128).ok_or(Error::Overflow)?);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Date, Error, Week, Zone
+    };
+
+    use crate::types::{
+        planets::{
+            earth::{
+                calendar::{
+                    cutover::{Cutover},
+                    functions::{weeks_in_year, us_weeks_in_year}
+                }
+            }
+        }
+    };
+
+    #[test]
+    fn test_iso_week() {
+        for ((year, month, day), week) in [
+            ((2024, 3, 1), (2024, 9, Week::Friday)),
+            ((2024, 12, 30), (2025, 1, Week::Monday)),
+            ((2021, 1, 3), (2020, 53, Week::Sunday)),
+            ((2026, 12, 31), (2026, 53, Week::Thursday)),
+            ((1, 1, 1), (1, 1, Week::Monday))
+        ] {
+            let date: Date = Date::new(CalendarView::Gregorian, year, month, day, Zone::default()).unwrap();
+
+            assert_eq!(date.iso_week(), Ok(week));
+            assert!(Date::from_iso_week(CalendarView::Gregorian, week.0, week.1, week.2, Zone::default()).unwrap().same_civil_fields(&date));
+        }
+
+        for (year, weeks) in [(2015, 53), (2020, 53), (2021, 52), (2026, 53), (2100, 52)] {
+            assert_eq!(weeks_in_year(CalendarView::Gregorian, year), Ok(weeks), "{year}");
+        }
+
+        // Юлианский 2024 год начинается с воскресенья: 1 января относится к последней неделе 2023 года.
+        assert_eq!(Date::new(CalendarView::Julian, 2024, 1, 1, Zone::default()).unwrap().iso_week(), Ok((2023, 52, Week::Sunday)));
+        assert_eq!(Date::new(CalendarView::Julian, 2024, 3, 1, Zone::default()).unwrap().iso_week(), Ok((2024, 9, Week::Thursday)));
+
+        assert_eq!(Date::from_iso_week(CalendarView::Gregorian, 2021, 53, Week::Monday, Zone::default()), Err(Error::InvalidWeek(53)));
+        assert_eq!(Date::from_iso_week(CalendarView::Gregorian, 2021, 0, Week::Monday, Zone::default()), Err(Error::InvalidWeek(0)));
+        assert_eq!(weeks_in_year(CalendarView::Gregorian, 0), Err(Error::InvalidYear(0)));
+    }
+
+    #[test]
+    fn test_us_week() {
+        for ((year, month, day), week) in [
+            ((2024, 1, 1), (2024, 1, Week::Monday)),
+            ((2024, 12, 29), (2025, 1, Week::Sunday)),
+            ((2022, 12, 31), (2022, 53, Week::Saturday)),
+            ((2023, 1, 1), (2023, 1, Week::Sunday))
+        ] {
+            let date: Date = Date::new(CalendarView::Gregorian, year, month, day, Zone::default()).unwrap();
+
+            assert_eq!(date.us_week(), Ok(week));
+            assert!(Date::from_us_week(CalendarView::Gregorian, week.0, week.1, week.2, Zone::default()).unwrap().same_civil_fields(&date));
+        }
+
+        assert_eq!((us_weeks_in_year(CalendarView::Gregorian, 2022), us_weeks_in_year(CalendarView::Gregorian, 2023)), (Ok(53), Ok(52)));
+        assert_eq!(us_weeks_in_year(CalendarView::Solar, 0), Err(Error::InvalidYear(0)));
+        assert_eq!(Date::new(CalendarView::Julian, 2024, 1, 1, Zone::default()).unwrap().us_week(), Ok((2024, 1, Week::Sunday)));
+    }
+
+    #[test]
+    fn test_week_sequence() {
+        // Из года перехода 1582 выпали 10 дней: 1 января - понедельник, 1 января 1583 - суббота.
+        let rome: CalendarView = CalendarView::Historical(Cutover::ROME);
+
+        assert_eq!(weeks_in_year(rome, 1582), Ok(51));
+
+        for view in [CalendarView::Gregorian, CalendarView::Julian, CalendarView::Solar, rome] {
+            for (week_of, first_day) in [(Date::iso_week as fn(&Date) -> Result<(u64, u8, Week), Error>, Week::Monday), (Date::us_week, Week::Sunday)] {
+                let mut date: Date = Date::new(view, 1575, 1, 1, Zone::default()).unwrap();
+                let mut previous: (u64, u8, Week) = week_of(&date).unwrap();
+
+                for _ in 0..5_000 {
+                    date = date.checked_add_days(1).unwrap();
+
+                    let current: (u64, u8, Week) = week_of(&date).unwrap();
+
+                    assert_eq!(current.2, previous.2.next(), "{date:?}");
+
+                    if current.0 == previous.0 && current.1 == previous.1 {
+                        assert_ne!(current.2, first_day);
+                    } else if current.0 == previous.0 {
+                        assert_eq!((current.1, current.2), (previous.1 + 1, first_day));
+                    } else {
+                        assert_eq!((current.0, current.1, current.2), (previous.0 + 1, 1, first_day));
+                    }
+
+                    previous = current;
+                }
+            }
+
+            for year in 1575..1600 {
+                for (week, weeks) in [(Date::iso_week as fn(&Date) -> Result<(u64, u8, Week), Error>, weeks_in_year(view, year).unwrap()), (Date::us_week, us_weeks_in_year(view, year).unwrap())] {
+                    let last: Date = Date::new(view, year + 1, 1, 1, Zone::default()).unwrap().checked_sub_days(7).unwrap();
+
+                    assert!(matches!(week(&last), Ok((y, w, _)) if (y, w) == (year, weeks) || (y, w) == (year, weeks - 1)));
+                }
+
+                for week in [1, weeks_in_year(view, year).unwrap()] {
+                    for week_day in [Week::Monday, Week::Thursday, Week::Sunday] {
+                        let date: Date = Date::from_iso_week(view, year, week, week_day, Zone::default()).unwrap();
+
+                        assert_eq!(date.iso_week(), Ok((year, week, week_day)));
+                    }
+                }
+
+                for week in [1, us_weeks_in_year(view, year).unwrap()] {
+                    for week_day in [Week::Sunday, Week::Wednesday, Week::Saturday] {
+                        let date: Date = Date::from_us_week(view, year, week, week_day, Zone::default()).unwrap();
+
+                        assert_eq!(date.us_week(), Ok((year, week, week_day)));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use super::{Date};
+
+use crate::types::{
+    error::{Error},
+    data::{
+        zone::{Zone}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                constants::{
+                    week::{Week, DAYS_IN_WEEK}
+                },
+                functions::{
+                    days_from_presentation_date, days_in_month
+                },
+                traits::{
+                    day_of_week::{RataDie}
+                }
+            }
+        },
+    },
+};
+
+// Сколько дней вперёд от дня недели from до ближайшего week_day (0 - тот же день).
+const fn days_ahead(from: Week, week_day: Week) -> u8 {
+    return (week_day.index() + DAYS_IN_WEEK - from.index()) % DAYS_IN_WEEK;
+}
+
+impl Date {
+    // n-й день недели месяца: 1 - первый, 2 - второй, ..., -1 - последний, -2 - предпоследний.
+    pub fn nth_weekday(view: CalendarView, year: u64, month: u8, week_day: Week, n: i8, time_zone: Zone) -> Result<Date, Error> {
+        let first: Date = Date::new(view, year, month, 1_u8, time_zone)?;
+
+        let last_day: u8 = days_in_month(view, year, month)?;
+
+        // Дни от начала месяца до последнего дня, у месяца перехода исторического календаря без пропущенных дней.
+        let month_days: u8 = (days_from_presentation_date(view, year, month, last_day) - days_from_presentation_date(view, year, month, 1_u8)) as u8 + 1_u8;
+
+        let day: i16 = match n {
+            0_i8 => return Err(Error::InvalidOccurrence(n)),
+            1_i8.. => days_ahead(<Date as RataDie>::from(view, year, month, 1_u8), week_day) as i16 + (n as i16 - 1_i16) * DAYS_IN_WEEK as i16,
+            _ => {
+                let from_end: u8 = days_ahead(week_day, <Date as RataDie>::from(view, year, month, last_day));
+
+                month_days as i16 - 1_i16 - from_end as i16 + (n as i16 + 1_i16) * DAYS_IN_WEEK as i16
+            }
+        };
+
+        if day < 0_i16 || day >= month_days as i16 {
+            return Err(Error::InvalidOccurrence(n));
+        }
+
+        return first.checked_add_days(day as u128);
+    }
+
+    // Ближайший week_day строго после даты.
+    pub fn next_weekday(&self, week_day: Week) -> Result<Date, Error> {
+        return self.checked_add_days(((days_ahead(<Date as RataDie>::week_day(self), week_day) + DAYS_IN_WEEK - 1_u8) % DAYS_IN_WEEK + 1_u8) as u128);
+    }
+
+    // Ближайший week_day строго до даты.
+    pub fn previous_weekday(&self, week_day: Week) -> Result<Date, Error> {
+        return self.checked_sub_days(((days_ahead(week_day, <Date as RataDie>::week_day(self)) + DAYS_IN_WEEK - 1_u8) % DAYS_IN_WEEK + 1_u8) as u128);
+    }
+
+    pub fn on_or_after(&self, week_day: Week) -> Result<Date, Error> {
+        return self.checked_add_days(days_ahead(<Date as RataDie>::week_day(self), week_day) as u128);
+    }
+
+    pub fn on_or_before(&self, week_day: Week) -> Result<Date, Error> {
+        return self.checked_sub_days(days_ahead(week_day, <Date as RataDie>::week_day(self)) as u128);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Date, Error, RataDie, Week, Zone
+    };
+
+    use crate::types::{
+        planets::{
+            earth::{
+                calendar::{
+                    cutover::{Cutover}
+                }
+            }
+        }
+    };
+
+    const ROME: CalendarView = CalendarView::Historical(Cutover::ROME);
+
+    fn day_of(date: Result<Date, Error>) -> Result<(u8, u8), Error> {
+        return date.map(|date| (date.month().index(), date.day()));
+    }
+
+    #[test]
+    fn test_nth_weekday() {
+        for ((view, year, month, week_day, n), day) in [
+            ((CalendarView::Gregorian, 2024, 1, Week::Monday, 3), Ok((1, 15))),
+            ((CalendarView::Gregorian, 2024, 5, Week::Friday, -1), Ok((5, 31))),
+            ((CalendarView::Gregorian, 2024, 5, Week::Monday, -1), Ok((5, 27))),
+            ((CalendarView::Gregorian, 2024, 11, Week::Thursday, 4), Ok((11, 28))),
+            ((CalendarView::Gregorian, 2024, 2, Week::Thursday, 5), Ok((2, 29))),
+            ((CalendarView::Gregorian, 2024, 2, Week::Thursday, -5), Ok((2, 1))),
+            ((CalendarView::Gregorian, 2024, 2, Week::Friday, 5), Err(Error::InvalidOccurrence(5))),
+            ((CalendarView::Gregorian, 2024, 2, Week::Thursday, -6), Err(Error::InvalidOccurrence(-6))),
+            ((CalendarView::Gregorian, 2024, 2, Week::Thursday, 0), Err(Error::InvalidOccurrence(0))),
+            ((CalendarView::Gregorian, 2024, 13, Week::Thursday, 1), Err(Error::InvalidMonth(13))),
+            ((CalendarView::Julian, 2024, 3, Week::Sunday, 1), Ok((3, 4))),
+            // Октябрь 1582 года: за четвергом 4 сразу идёт пятница 15.
+            ((ROME, 1582, 10, Week::Monday, 2), Ok((10, 18))),
+            ((ROME, 1582, 10, Week::Thursday, -1), Ok((10, 28))),
+            ((ROME, 1582, 10, Week::Thursday, -3), Ok((10, 4))),
+            ((ROME, 1582, 10, Week::Monday, 4), Err(Error::InvalidOccurrence(4)))
+        ] {
+            assert_eq!(day_of(Date::nth_weekday(view, year, month, week_day, n, Zone::default())), day, "{view:?} {year}-{month} {week_day:?} {n}");
+        }
+
+        for view in [CalendarView::Gregorian, CalendarView::Julian, CalendarView::Solar, ROME] {
+            for month in 1..=12 {
+                let mut days: Vec<Date> = Vec::new();
+                let mut date: Date = Date::new(view, 1582, month, 1, Zone::default()).unwrap();
+
+                while date.month().index() == month {
+                    days.push(date);
+                    date = date.checked_add_days(1).unwrap();
+                }
+
+                for week_day in 1..=7 {
+                    let week_day: Week = Week::from(week_day);
+
+                    let matches: Vec<Date> = days.iter().copied().filter(|date| <Date as RataDie>::week_day(date) == week_day).collect();
+
+                    for n in 1..=6_i8 {
+                        assert_eq!(Date::nth_weekday(view, 1582, month, week_day, n, Zone::default()).ok(), matches.get(n as usize - 1).copied());
+                        assert_eq!(Date::nth_weekday(view, 1582, month, week_day, -n, Zone::default()).ok(), matches.len().checked_sub(n as usize).map(|index| matches[index]));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_and_previous_weekday() {
+        // 1 марта 2024 года - пятница.
+        let date: Date = Date::new(CalendarView::Gregorian, 2024, 3, 1, Zone::default()).unwrap();
+
+        assert_eq!(day_of(date.next_weekday(Week::Friday)), Ok((3, 8)));
+        assert_eq!(day_of(date.next_weekday(Week::Sunday)), Ok((3, 3)));
+        assert_eq!(day_of(date.previous_weekday(Week::Friday)), Ok((2, 23)));
+        assert_eq!(day_of(date.previous_weekday(Week::Thursday)), Ok((2, 29)));
+        assert_eq!(day_of(date.on_or_after(Week::Friday)), Ok((3, 1)));
+        assert_eq!(day_of(date.on_or_after(Week::Thursday)), Ok((3, 7)));
+        assert_eq!(day_of(date.on_or_before(Week::Friday)), Ok((3, 1)));
+        assert_eq!(day_of(date.on_or_before(Week::Sunday)), Ok((2, 25)));
+
+        // Первый день эры григорианского календаря - понедельник.
+        let first: Date = Date::new(CalendarView::Gregorian, 1, 1, 1, Zone::default()).unwrap();
+
+        assert_eq!(first.previous_weekday(Week::Sunday), Err(Error::OutsideEra(CalendarView::Gregorian)));
+        assert_eq!(day_of(first.on_or_before(Week::Monday)), Ok((1, 1)));
+
+        let thursday: Date = Date::new(ROME, 1582, 10, 4, Zone::default()).unwrap();
+
+        assert_eq!(day_of(thursday.next_weekday(Week::Friday)), Ok((10, 15)));
+        assert_eq!(day_of(thursday.next_weekday(Week::Thursday)), Ok((10, 21)));
+    }
+}
@@ -0,0 +1,294 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::types::data::date_time::{DateTime};
+
+use crate::types::{
+    error::{Error},
+    data::{
+        date::{Date},
+        time::{Time},
+        instant::{Instant},
+        zone::{Zone},
+        duration::{Duration},
+        month_end::{MonthEnd}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                constants::{
+                    hours::{HOURS_IN_A_DAY},
+                    minutes::{MINUTES_IN_HOUR},
+                    seconds::{SECONDS_IN_HOUR, SECONDS_IN_MINUTE, NANOSECONDS_IN_SECOND},
+                    months::{Months},
+                },
+            }
+        },
+    },
+};
+
+impl DateTime {
+    pub fn utc(view: CalendarView) -> DateTime {
+        return DateTime::of(Date::utc(view));
+    }
+
+    pub fn now(view: CalendarView, time_zone: Zone) -> DateTime {
+        return DateTime::of(Date::now(view, time_zone));
+    }
+
+    #[cfg(any(
+        feature = "platform_specific_functions_darwin",
+        feature = "platform_specific_functions_unix",
+        feature = "platform_specific_functions_windows"
+    ))]
+    pub fn local(view: CalendarView) -> DateTime {
+        return DateTime::of(Date::local(view));
+    }
+
+    pub fn from(view: CalendarView, instant: Instant, time_zone: Zone) -> DateTime {
+        return DateTime::of(Date::from(view, instant, time_zone));
+    }
+
+    pub fn try_utc(view: CalendarView) -> Result<DateTime, Error> {
+        return Ok(DateTime::of(Date::try_utc(view)?));
+    }
+
+    pub fn try_now(view: CalendarView, time_zone: Zone) -> Result<DateTime, Error> {
+        return Ok(DateTime::of(Date::try_now(view, time_zone)?));
+    }
+
+    #[cfg(any(
+        feature = "platform_specific_functions_darwin",
+        feature = "platform_specific_functions_unix",
+        feature = "platform_specific_functions_windows"
+    ))]
+    pub fn try_local(view: CalendarView) -> Result<DateTime, Error> {
+        return Ok(DateTime::of(Date::try_local(view)?));
+    }
+
+    pub fn try_from(view: CalendarView, instant: Instant, time_zone: Zone) -> Result<DateTime, Error> {
+        return Ok(DateTime::of(Date::try_from(view, instant, time_zone)?));
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(view: CalendarView, year: u64, month: u8, day: u8, hours: u8, minutes: u8, seconds: u8, nanoseconds: u32, time_zone: Zone) -> Result<DateTime, Error> {
+        if hours >= HOURS_IN_A_DAY {
+            return Err(Error::InvalidHour(hours));
+        } else if minutes >= MINUTES_IN_HOUR {
+            return Err(Error::InvalidMinute(minutes));
+        } else if seconds as u128 >= SECONDS_IN_MINUTE {
+            return Err(Error::InvalidSecond(seconds));
+        } else if nanoseconds >= NANOSECONDS_IN_SECOND {
+            return Err(Error::InvalidNanoseconds(nanoseconds));
+        }
+
+        let mut date: Date = Date::new(view, year, month, day, time_zone)?;
+
+        date.unix_time += (hours as u128 * SECONDS_IN_HOUR + minutes as u128 * SECONDS_IN_MINUTE + seconds as u128) as i128;
+        date.nanoseconds = nanoseconds;
+
+        return Ok(DateTime::of(date));
+    }
+
+    pub fn to_view(&self, view: CalendarView) -> Result<DateTime, Error> {
+        return Ok(DateTime::of(self.date.to_view(view)?));
+    }
+
+    pub fn to_zone(&self, time_zone: Zone) -> Result<DateTime, Error> {
+        return DateTime::try_from(self.date.view, self.instant(), time_zone);
+    }
+
+    pub fn checked_add_duration(&self, duration: Duration, policy: MonthEnd) -> Result<DateTime, Error> {
+        return Ok(DateTime::of(self.date.checked_add_duration(duration, policy)?));
+    }
+
+    pub fn checked_sub_duration(&self, duration: Duration, policy: MonthEnd) -> Result<DateTime, Error> {
+        return Ok(DateTime::of(self.date.checked_sub_duration(duration, policy)?));
+    }
+
+    pub const fn instant(&self) -> Instant {
+        return self.date.instant();
+    }
+
+    // Время выводится из той же даты, поэтому достаточно сравнить поля даты.
+    pub fn same_civil_fields(&self, other: &DateTime) -> bool {
+        return self.date.same_civil_fields(&other.date);
+    }
+
+    pub const fn date(&self) -> Date {
+        return self.date;
+    }
+
+    pub const fn time(&self) -> Time {
+        return self.time;
+    }
+
+    pub const fn year(&self) -> u64 {
+        return self.date.year;
+    }
+
+    pub fn month(&self) -> Months {
+        return Months::from(self.date.month);
+    }
+
+    pub const fn day(&self) -> u8 {
+        return self.date.day;
+    }
+
+    pub const fn hours(&self) -> u8 {
+        return self.time.hours;
+    }
+
+    pub const fn minutes(&self) -> u8 {
+        return self.time.minutes;
+    }
+
+    pub const fn seconds(&self) -> u8 {
+        return self.time.seconds;
+    }
+
+    pub const fn nanoseconds(&self) -> u32 {
+        return self.time.nanoseconds;
+    }
+
+    pub const fn time_zone(&self) -> Zone {
+        return self.date.time_zone;
+    }
+
+    pub const fn unix_time(&self) -> i128 {
+        return self.date.unix_time();
+    }
+
+    pub const fn era_days(&self) -> u128 {
+        return self.date.era_days;
+    }
+
+    pub const fn view(&self) -> CalendarView {
+        return self.date.view;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cmp::{Ordering},
+        collections::{BTreeSet}
+    };
+
+    use super::{
+        CalendarView, Date, DateTime, Error, Instant, Zone
+    };
+
+    use crate::types::{
+        data::{
+            zone::{Sign}
+        }
+    };
+
+    #[test]
+    fn test_date_time_from_single_instant() {
+        let time_zone: Zone = Zone { sign: Sign::Signed, hours: 3_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        // 1970-01-01 02:59:59.5 UTC is still 1969-12-31 in UTC-3.
+        let instant: Instant = Instant::new(10_799_i128, 500_000_000_u32).unwrap();
+
+        let date_time: DateTime = DateTime::from(CalendarView::Gregorian, instant, time_zone);
+
+        assert_eq!(
+            (date_time.year(), date_time.month().index(), date_time.day(), date_time.hours(), date_time.minutes(), date_time.seconds(), date_time.nanoseconds()),
+            (1969, 12, 31, 23, 59, 59, 500_000_000)
+        );
+        assert_eq!(date_time.date(), Date::from(CalendarView::Gregorian, instant, time_zone));
+        assert_eq!((date_time.time().unix_time, date_time.unix_time(), date_time.instant()), (10_799_i128, 10_799_i128, instant));
+
+        let built: DateTime = DateTime::new(CalendarView::Gregorian, 1969, 12, 31, 23, 59, 59, 500_000_000, time_zone).unwrap();
+
+        assert_eq!(built, date_time);
+        assert_eq!(DateTime::new(CalendarView::Gregorian, 1969, 12, 31, 24, 0, 0, 0, time_zone), Err(Error::InvalidHour(24)));
+        assert_eq!(DateTime::new(CalendarView::Gregorian, 1969, 12, 31, 0, 60, 0, 0, time_zone), Err(Error::InvalidMinute(60)));
+        assert_eq!(DateTime::new(CalendarView::Gregorian, 1969, 12, 31, 0, 0, 60, 0, time_zone), Err(Error::InvalidSecond(60)));
+    }
+
+    #[test]
+    fn test_date_time_to_view_and_zone() {
+        let west: Zone = Zone { sign: Sign::Signed, hours: 5_u8, minutes: 0_u8, seconds: 0_u8 };
+        let east: Zone = Zone { sign: Sign::Unsigned, hours: 9_u8, minutes: 30_u8, seconds: 0_u8 };
+
+        let date_time: DateTime = DateTime::new(CalendarView::Gregorian, 2024, 2, 29, 20, 15, 0, 42, west).unwrap();
+
+        let julian: DateTime = date_time.to_view(CalendarView::Julian).unwrap();
+
+        assert_eq!((julian.year(), julian.month().index(), julian.day(), julian.view()), (2024, 2, 16, CalendarView::Julian));
+        assert_eq!((julian.hours(), julian.minutes(), julian.nanoseconds(), julian.unix_time()), (20, 15, 42, date_time.unix_time()));
+
+        let tokyo: DateTime = date_time.to_zone(east).unwrap();
+
+        assert_eq!(
+            (tokyo.year(), tokyo.month().index(), tokyo.day(), tokyo.hours(), tokyo.minutes(), tokyo.nanoseconds(), tokyo.time_zone()),
+            (2024, 3, 1, 10, 45, 42, east)
+        );
+        assert_eq!(tokyo.to_zone(west), Ok(date_time));
+        assert!(tokyo.to_zone(west).unwrap().same_civil_fields(&date_time));
+    }
+
+    #[test]
+    fn test_date_time_instant_ordering() {
+        let (utc, east): (Zone, Zone)
+        =
+        (
+            Zone::default(),
+            Zone { sign: Sign::Unsigned, hours: 3_u8, minutes: 0_u8, seconds: 0_u8 }
+        );
+
+        let (london, moscow): (DateTime, DateTime)
+        =
+        (
+            DateTime::new(CalendarView::Gregorian, 2024, 3, 1, 1, 0, 0, 0, utc).unwrap(),
+            DateTime::new(CalendarView::Gregorian, 2024, 3, 1, 4, 0, 0, 0, east).unwrap()
+        );
+
+        assert_eq!(london, moscow);
+        assert_eq!(london.cmp(&moscow), Ordering::Equal);
+        assert!(!london.same_civil_fields(&moscow));
+        assert!(london.to_zone(east).unwrap().same_civil_fields(&moscow));
+
+        let (earlier, julian): (DateTime, DateTime)
+        =
+        (
+            DateTime::new(CalendarView::Gregorian, 2024, 3, 1, 3, 0, 0, 0, east).unwrap(),
+            london.to_view(CalendarView::Julian).unwrap()
+        );
+
+        assert!(earlier < london && earlier < moscow);
+
+        let unique: BTreeSet<DateTime> = [moscow, earlier, julian, london].into_iter().collect();
+        assert_eq!(unique.len(), 2);
+        assert!(unique.into_iter().next().unwrap().same_civil_fields(&earlier));
+    }
+}
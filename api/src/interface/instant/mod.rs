@@ -0,0 +1,129 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::types::data::instant::{Instant};
+
+use crate::types::{
+    error::{Error},
+    data::{
+        date::{Date},
+        date_time::{DateTime},
+        time::{Time},
+        zone::{Zone}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView}
+            }
+        },
+    },
+};
+
+impl Instant {
+    pub fn now() -> Instant {
+        return Instant::system();
+    }
+
+    pub const fn from_seconds(seconds: i128) -> Instant {
+        return Instant { seconds: seconds, nanoseconds: 0_u32 };
+    }
+
+    pub const fn new(seconds: i128, nanoseconds: u32) -> Result<Instant, Error> {
+        return Instant::try_of(seconds, nanoseconds);
+    }
+
+    pub const fn seconds(&self) -> i128 {
+        return self.seconds;
+    }
+
+    pub const fn nanoseconds(&self) -> u32 {
+        return self.nanoseconds;
+    }
+
+    pub fn to_time(&self, time_zone: Zone) -> Result<Time, Error> {
+        return Time::try_from(*self, time_zone);
+    }
+
+    pub fn to_date(&self, view: CalendarView, time_zone: Zone) -> Result<Date, Error> {
+        return Date::try_from(view, *self, time_zone);
+    }
+
+    pub fn to_date_time(&self, view: CalendarView, time_zone: Zone) -> Result<DateTime, Error> {
+        return DateTime::try_from(view, *self, time_zone);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Error, Instant, Zone
+    };
+
+    use crate::types::{
+        data::{
+            zone::{Sign}
+        }
+    };
+
+    #[test]
+    fn test_instant_to_civil_and_back() {
+        let zones: [Zone; 3] = [
+            Zone { sign: Sign::Signed, hours: 11_u8, minutes: 0_u8, seconds: 0_u8 },
+            Zone { sign: Sign::Unsigned, hours: 0_u8, minutes: 0_u8, seconds: 0_u8 },
+            Zone { sign: Sign::Unsigned, hours: 14_u8, minutes: 0_u8, seconds: 0_u8 }
+        ];
+
+        for seconds in [-62135596800_i128 + 14 * 3600, -1_i128, 0_i128, 1_709_164_799_i128] {
+            let instant: Instant = Instant::new(seconds, 250_u32).unwrap();
+
+            for time_zone in zones {
+                for view in [CalendarView::Solar, CalendarView::Julian, CalendarView::Gregorian] {
+                    let date = instant.to_date(view, time_zone).unwrap();
+
+                    assert_eq!(date.instant(), instant);
+                    assert_eq!(date.unix_time(), seconds);
+                    assert_eq!(date.time_zone(), time_zone);
+
+                    let date_time = instant.to_date_time(view, time_zone).unwrap();
+
+                    assert_eq!(date_time.instant(), instant);
+                    assert_eq!(date_time.date(), date);
+                }
+
+                let time = instant.to_time(time_zone).unwrap();
+
+                assert_eq!((time.unix_time, time.nanoseconds), (seconds, 250_u32));
+            }
+        }
+
+        assert_eq!(Instant::new(0, 1_000_000_000), Err(Error::InvalidNanoseconds(1_000_000_000)));
+        assert_eq!(Instant::from_seconds(i128::MAX).to_time(zones[2]), Err(Error::Overflow));
+        assert_eq!(Instant::from_seconds(-62135596800_i128).to_date(CalendarView::Gregorian, zones[0]), Err(Error::OutsideEra(CalendarView::Gregorian)));
+    }
+}
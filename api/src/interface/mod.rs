@@ -28,10 +28,16 @@
 
 mod time;
 mod date;
+mod date_time;
+mod instant;
 mod uptime;
+mod range;
 
 pub use time::{Time};
 pub use date::{Date};
+pub use date_time::{DateTime};
+pub use instant::{Instant};
+pub use range::{DateRange, Step};
 
 #[cfg(any(
     feature = "platform_specific_functions_darwin",
@@ -0,0 +1,389 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+pub use crate::types::{
+    data::{
+        range::{DateRange, Step}
+    }
+};
+
+use crate::types::{
+    error::{Error},
+    data::{
+        date::{Date},
+        month_end::{MonthEnd}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                constants::{
+                    year::{LEAP_DAYS_YEAR},
+                    seconds::{SECONDS_IN_DAY},
+                    week::{DAYS_IN_WEEK},
+                },
+                functions::{
+                    days_from_presentation_date, days_in_month, validate_presentation_date
+                }
+            }
+        },
+    },
+    counter::{
+        unix_time::{
+            constants::{
+                days::{UNIX_TIME_START_AFTER_DAY}
+            },
+        }
+    }
+};
+
+impl DateRange {
+    pub fn inclusive(start: Date, end: Date, step: Step) -> Result<DateRange, Error> {
+        return DateRange::new(start, end, step, true);
+    }
+
+    pub fn exclusive(start: Date, end: Date, step: Step) -> Result<DateRange, Error> {
+        return DateRange::new(start, end, step, false);
+    }
+
+    // Направление обхода определяется порядком границ: если конец раньше начала, даты идут в прошлое.
+    pub fn new(start: Date, end: Date, step: Step, inclusive: bool) -> Result<DateRange, Error> {
+        if matches!(step, Step::Days(0_u128) | Step::Weeks(0_u128) | Step::Months(0_u128) | Step::Years(0_u64)) {
+            return Err(Error::InvalidStep);
+        }
+
+        return Ok(DateRange {
+            start: start,
+            end: end,
+            step: step,
+            inclusive: inclusive,
+            forward: start.era_days <= end.era_days,
+            month_end: MonthEnd::default(),
+            day_seconds: start.unix_time.rem_euclid(SECONDS_IN_DAY as i128),
+            index: 0_u128,
+            current: Some(start)
+        });
+    }
+
+    // Для шагов в месяцах и годах политика применяется к дню начала диапазона, а не к предыдущему элементу:
+    // 31 января -> 29 февраля -> 31 марта. При MonthEnd::Reject несуществующие даты пропускаются.
+    pub fn with_month_end(mut self, policy: MonthEnd) -> DateRange {
+        self.month_end = policy;
+        return self;
+    }
+
+    pub fn start(&self) -> Date {
+        return self.start;
+    }
+
+    pub fn end(&self) -> Date {
+        return self.end;
+    }
+
+    pub fn step(&self) -> Step {
+        return self.step;
+    }
+
+    pub fn is_inclusive(&self) -> bool {
+        return self.inclusive;
+    }
+
+    pub fn is_forward(&self) -> bool {
+        return self.forward;
+    }
+
+    fn contains(&self, date: &Date) -> bool {
+        return match (self.forward, self.inclusive) {
+            (true, true) => date.era_days <= self.end.era_days,
+            (true, false) => date.era_days < self.end.era_days,
+            (false, true) => date.era_days >= self.end.era_days,
+            (false, false) => date.era_days > self.end.era_days,
+        };
+    }
+
+    // Недели и годы переводятся в дни и месяцы при каждом шаге: шаг, который не помещается в u128, просто завершает обход.
+    fn advance(&mut self, date: &Date) -> Option<Date> {
+        return match self.step {
+            Step::Days(days) => self.shift_days(date, days, self.forward),
+            Step::Weeks(weeks) => self.shift_days(date, weeks.checked_mul(DAYS_IN_WEEK as u128)?, self.forward),
+            Step::Months(months) => self.advance_months(months),
+            Step::Years(years) => self.advance_months((years as u128).checked_mul(self.start.view.system().months_in_year() as u128)?)
+        };
+    }
+
+    fn advance_months(&mut self, months: u128) -> Option<Date> {
+        loop {
+            self.index = self.index.checked_add(1_u128)?;
+
+            let offset: u128 = self.index.checked_mul(months)?;
+
+            match self.nth_month(offset) {
+                Ok(next) => return Some(next),
+                Err(Error::InvalidDay(_)) | Err(Error::SkippedDay(_)) => continue,
+                Err(_) => return None,
+            }
+        }
+    }
+
+    // Сдвигает дату на количество дней, перебирая месяцы от текущего дня представления без повторного поиска года.
+    // Для шагов больше года и для исторического календаря с пропущенными днями дата пересчитывается через день эры.
+    fn shift_days(&self, date: &Date, days: u128, forward: bool) -> Option<Date> {
+        let view: CalendarView = date.view;
+
+        if days > LEAP_DAYS_YEAR as u128 || matches!(view, CalendarView::Historical(_)) {
+            return if forward {
+                date.with_era_days(date.era_days.checked_add(days)?).ok()
+            } else {
+                date.with_era_days(date.era_days.checked_sub(days)?).ok()
+            };
+        }
+
+        let (mut year, mut month): (u64, u8) = (date.year, date.month);
+
+        let (era_days, day): (u128, u8) = if forward {
+            let mut day: u128 = date.day as u128 + days;
+
+            loop {
+                let last_day: u128 = days_in_month(view, year, month).ok()? as u128;
+
+                if day <= last_day {
+                    break;
+                }
+
+                day -= last_day;
+
+                if month == view.system().months_in_year() {
+                    (year, month) = (year.checked_add(1_u64)?, 1_u8);
+                } else {
+                    month += 1_u8;
+                }
+            }
+
+            (date.era_days.checked_add(days)?, day as u8)
+        } else {
+            let mut day: i128 = date.day as i128 - days as i128;
+
+            while day < 1_i128 {
+                if month == 1_u8 {
+                    (year, month) = (year.checked_sub(1_u64)?, view.system().months_in_year());
+                } else {
+                    month -= 1_u8;
+                }
+
+                day += days_in_month(view, year, month).ok()? as i128;
+            }
+
+            (date.era_days.checked_sub(days)?, day as u8)
+        };
+
+        if era_days == 0_u128 || year == 0_u64 {
+            return None;
+        }
+
+        return Some(self.compose(year, month, day, era_days));
+    }
+
+    fn nth_month(&self, offset: u128) -> Result<Date, Error> {
+        let start: &Date = &self.start;
+
+        let months_in_year: u128 = start.view.system().months_in_year() as u128;
+
+        let anchor: u128 = start.year as u128 * months_in_year + (start.month - 1_u8) as u128;
+
+        let total_months: u128 = if self.forward {
+            anchor.checked_add(offset).ok_or(Error::Overflow)?
+        } else {
+            anchor.checked_sub(offset).ok_or(Error::OutsideEra(start.view))?
+        };
+
+        let year: u128 = total_months / months_in_year;
+
+        if year > u64::MAX as u128 {
+            return Err(Error::Overflow);
+        } else if year == 0_u128 {
+            return Err(Error::OutsideEra(start.view));
+        }
+
+        let (year, month): (u64, u8) = (year as u64, (total_months % months_in_year) as u8 + 1_u8);
+
+        let last_day: u8 = days_in_month(start.view, year, month)?;
+
+        let (day, overflow_days): (u8, u8) = if start.day <= last_day {
+            (start.day, 0_u8)
+        } else {
+            match self.month_end {
+                MonthEnd::Clamp => (last_day, 0_u8),
+                MonthEnd::RollOver => (last_day, start.day - last_day),
+                MonthEnd::Reject => return Err(Error::InvalidDay(start.day)),
+            }
+        };
+
+        // Отсекает дни до начала эры (у юлианского календаря два) и дни, пропущенные реформой календаря.
+        validate_presentation_date(start.view, year, month, day)?;
+
+        let era_days: u128 = days_from_presentation_date(start.view, year, month, day) - start.view.system().epoch_offset();
+
+        let date: Date = self.compose(year, month, day, era_days);
+
+        if overflow_days > 0_u8 {
+            return self.shift_days(&date, overflow_days as u128, true).ok_or(Error::Overflow);
+        }
+
+        return Ok(date);
+    }
+
+    fn compose(&self, year: u64, month: u8, day: u8, era_days: u128) -> Date {
+        let unix_time: i128 = (era_days as i128 - (UNIX_TIME_START_AFTER_DAY + 1_u128) as i128) * SECONDS_IN_DAY as i128 + self.day_seconds;
+
+        return Date {
+            day: day,
+            month: month,
+            year: year,
+            era_days: era_days,
+            unix_time: unix_time,
+            nanoseconds: self.start.nanoseconds,
+            time_zone: self.start.time_zone,
+            view: self.start.view
+        };
+    }
+}
+
+impl Iterator for DateRange {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Date> {
+        let current: Date = self.current?;
+
+        if !self.contains(&current) {
+            self.current = None;
+            return None;
+        }
+
+        self.current = self.advance(&current);
+
+        return Some(current);
+    }
+}
+
+impl std::iter::FusedIterator for DateRange {}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Date, DateRange, Error, MonthEnd, Step
+    };
+
+    use crate::types::{
+        data::{
+            instant::{Instant},
+            zone::{Sign, Zone}
+        }
+    };
+
+    #[test]
+    fn test_range_days_matches_arithmetic() {
+        let time_zone: Zone = Zone { sign: Sign::Unsigned, hours: 2_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        for view in [CalendarView::Solar, CalendarView::Julian, CalendarView::Gregorian] {
+            let start: Date = Date::from(view, Instant::from_seconds(946_684_800_i128 - 40 * 86400_i128 + 3600_i128), time_zone);
+
+            for step in [1_u128, 3_u128, 31_u128, 400_u128] {
+                let end: Date = start.checked_add_days(1600).unwrap();
+
+                let forward: Vec<Date> = DateRange::inclusive(start, end, Step::Days(step)).unwrap().collect();
+
+                assert_eq!(forward.len() as u128, 1600 / step + 1);
+
+                for (index, date) in forward.iter().enumerate() {
+                    assert_eq!(*date, start.checked_add_days(index as u128 * step).unwrap());
+                }
+
+                let backward: Vec<Date> = DateRange::inclusive(end, start, Step::Days(step)).unwrap().collect();
+
+                for (index, date) in backward.iter().enumerate() {
+                    assert_eq!(*date, end.checked_sub_days(index as u128 * step).unwrap());
+                }
+            }
+
+            assert_eq!(DateRange::exclusive(start, start.checked_add_weeks(3).unwrap(), Step::Weeks(1)).unwrap().count(), 3);
+            assert_eq!(DateRange::inclusive(start, start.checked_add_weeks(3).unwrap(), Step::Weeks(1)).unwrap().count(), 4);
+            assert_eq!(DateRange::exclusive(start, start, Step::Days(1)).unwrap().count(), 0);
+        }
+    }
+
+    #[test]
+    fn test_range_months_anchored_to_start() {
+        let time_zone: Zone = Zone { sign: Sign::Unsigned, hours: 0_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        let start: Date = Date::new(CalendarView::Gregorian, 2024, 1, 31, time_zone).unwrap();
+        let end: Date = Date::new(CalendarView::Gregorian, 2024, 6, 30, time_zone).unwrap();
+
+        let days = |range: DateRange| -> Vec<(u8, u8)> {
+            return range.map(|date| (date.month().index(), date.day())).collect();
+        };
+
+        let range: DateRange = DateRange::inclusive(start, end, Step::Months(1)).unwrap();
+
+        assert_eq!(days(range), vec![(1, 31), (2, 29), (3, 31), (4, 30), (5, 31), (6, 30)]);
+        assert_eq!(days(range.with_month_end(MonthEnd::Reject)), vec![(1, 31), (3, 31), (5, 31)]);
+        assert_eq!(days(range.with_month_end(MonthEnd::RollOver)), vec![(1, 31), (3, 2), (3, 31), (5, 1), (5, 31)]);
+
+        for date in range {
+            assert_eq!(date, start.add_months((date.month().index() - 1) as u128, MonthEnd::Clamp).unwrap());
+        }
+
+        let leap: Date = Date::new(CalendarView::Julian, 1900, 2, 29, time_zone).unwrap();
+        let first: Date = Date::new(CalendarView::Julian, 1, 3, 1, time_zone).unwrap();
+
+        let years: Vec<Date> = DateRange::exclusive(leap, first, Step::Years(100)).unwrap().collect();
+
+        assert_eq!(years.len(), 19);
+        assert!(years.iter().all(|date| date.month().index() == 2 && date.day() == 29 && date.view() == CalendarView::Julian));
+        assert_eq!(years.last().map(|date| date.year()), Some(100));
+    }
+
+    #[test]
+    fn test_range_invalid_step() {
+        let date: Date = Date::from(CalendarView::Gregorian, Instant::from_seconds(0_i128), Zone::default());
+
+        assert_eq!(DateRange::inclusive(date, date, Step::Days(0)), Err(Error::InvalidStep));
+        assert_eq!(DateRange::inclusive(date, date, Step::Years(0)), Err(Error::InvalidStep));
+        assert_eq!(DateRange::inclusive(date, date, Step::Weeks(u128::MAX)).unwrap().collect::<Vec<Date>>(), vec![date]);
+        assert_eq!(DateRange::inclusive(date, date, Step::Years(u64::MAX)).unwrap().collect::<Vec<Date>>(), vec![date]);
+
+        for step in [Step::Days(3), Step::Weeks(2), Step::Months(4), Step::Years(5)] {
+            assert_eq!(DateRange::inclusive(date, date, step).unwrap().step(), step);
+        }
+
+        let first: Date = Date::new(CalendarView::Gregorian, 1, 1, 2, Zone::default()).unwrap();
+        let mut range: DateRange = DateRange::inclusive(first, Date::new(CalendarView::Gregorian, 1, 1, 1, Zone::default()).unwrap(), Step::Days(5)).unwrap();
+
+        assert_eq!(range.next(), Some(first));
+        assert_eq!(range.next(), None);
+    }
+}
@@ -29,7 +29,9 @@
 pub use crate::types::data::time::{Time};
 
 use crate::types::{
+    error::{Error},
     data::{
+        instant::{Instant},
         zone::{Sign, Zone}
     }
 };
@@ -46,18 +48,17 @@ use crate::platform::{
 impl Time {
     pub fn utc() -> Time {
         return Self::of(
-            Self::unix().as_secs() as u128,
+            Instant::now(),
             Zone {
                 sign: Sign::Unsigned,
                 hours: 0, minutes: 0,
                 seconds: 0
-            },
-            true
+            }
         );
     }
 
     pub fn now(time_zone: Zone) -> Time {
-        return Self::of(Self::unix().as_secs() as u128, time_zone, false);
+        return Self::of(Instant::now(), time_zone);
     }
 
     #[cfg(any(
@@ -66,11 +67,43 @@ impl Time {
         feature = "platform_specific_functions_windows"
     ))]
     pub fn local() -> Time {
-        return Self::of(Self::unix().as_secs() as u128, local_timezone(), false);
+        return Self::of(Instant::now(), local_timezone());
+    }
+
+    pub fn from(instant: Instant, time_zone: Zone) -> Time {
+        return Self::of(instant, time_zone);
+    }
+
+    pub fn try_utc() -> Result<Time, Error> {
+        return Self::try_of(
+            Instant::now(),
+            Zone {
+                sign: Sign::Unsigned,
+                hours: 0, minutes: 0,
+                seconds: 0
+            }
+        );
+    }
+
+    pub fn try_now(time_zone: Zone) -> Result<Time, Error> {
+        return Self::try_of(Instant::now(), time_zone);
+    }
+
+    #[cfg(any(
+        feature = "platform_specific_functions_darwin",
+        feature = "platform_specific_functions_unix",
+        feature = "platform_specific_functions_windows"
+    ))]
+    pub fn try_local() -> Result<Time, Error> {
+        return Self::try_of(Instant::now(), local_timezone());
+    }
+
+    pub fn try_from(instant: Instant, time_zone: Zone) -> Result<Time, Error> {
+        return Self::try_of(instant, time_zone);
     }
 
-    pub fn from(unix: u128, time_zone: Zone, zone_in_unix: bool) -> Time {
-        return Self::of(unix, time_zone, zone_in_unix);
+    pub fn instant(&self) -> Instant {
+        return Instant { seconds: self.unix_time, nanoseconds: self.nanoseconds };
     }
 }
 
@@ -78,7 +111,7 @@ impl Time {
 mod tests {
 
     use super::{
-        Time,
+        Time, Instant, Error,
         Sign, Zone,
         local_timezone
     };
@@ -113,11 +146,11 @@ mod tests {
         for unix_time in (0..=current_seconds).step_by(SECONDS_IN_DAY as usize) {
             let time_c: time_t = unix_time as time_t;
 
-            if unsafe { gmtime_r(&time_c, &mut time_struct_libc) } == std::ptr::null_mut() {
+            if unsafe { gmtime_r(&time_c, &mut time_struct_libc) }.is_null() {
                 panic!("[ERROR]: Pointer is NULL (gmtime_r)!")
             }
 
-            let time: Time = Time::from(unix_time as u128, gmt_time_zone, false);
+            let time: Time = Time::from(Instant::from_seconds(unix_time as i128), gmt_time_zone);
 
             assert_eq!(
                 (
@@ -152,13 +185,13 @@ mod tests {
         for unix_time in (0..=current_seconds).step_by(SECONDS_IN_DAY as usize) {
             let time_c: time_t = unix_time as time_t;
 
-            if unsafe { localtime_r(&time_c, &mut time_struct_libc) } == std::ptr::null_mut() {
+            if unsafe { localtime_r(&time_c, &mut time_struct_libc) }.is_null() {
                 panic!("[ERROR]: Pointer is NULL (localtime_r)!")
             }
 
             if time_struct_libc.tm_gmtoff < 0 { sign = Sign::Signed } else { sign = Sign::Unsigned };
 
-            time = Time::from(unix_time as u128, Zone::from_seconds(sign, time_struct_libc.tm_gmtoff.unsigned_abs() as u32), false);
+            time = Time::from(Instant::from_seconds(unix_time as i128), Zone::from_seconds(sign, time_struct_libc.tm_gmtoff.unsigned_abs() as u32));
 
             assert_eq!(
                 (
@@ -176,4 +209,16 @@ mod tests {
             )
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_time_from_instant() {
+        let time_zone: Zone = Zone { sign: Sign::Unsigned, hours: 1_u8, minutes: 30_u8, seconds: 0_u8 };
+
+        let time: Time = Time::from(Instant::new(-1_i128, 500_000_000_u32).unwrap(), time_zone);
+
+        assert_eq!((time.hours, time.minutes, time.seconds, time.nanoseconds, time.unix_time), (1_u8, 29_u8, 59_u8, 500_000_000_u32, -1_i128));
+        assert_eq!(time.instant(), Instant::new(-1_i128, 500_000_000_u32).unwrap());
+        assert_eq!(Time::try_from(Instant::from_seconds(i128::MAX), time_zone), Err(Error::Overflow));
+        assert!(Time::now(time_zone).nanoseconds < 1_000_000_000_u32);
+    }
+}
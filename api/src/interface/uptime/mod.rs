@@ -44,6 +44,8 @@ impl Uptime {
         feature = "platform_specific_functions_windows"
     ))]
     pub fn absolute() -> Uptime {
-        return Uptime::from_seconds(uptime() as u128);
+        let (seconds, nanoseconds): (u64, u32) = uptime();
+
+        return Uptime::from_seconds(seconds as u128, nanoseconds);
     }
 }
@@ -30,6 +30,14 @@
 #![allow(non_snake_case)]
 #![allow(non_camel_case_types)]
 
+#![allow(clippy::needless_return)]
+#![allow(clippy::needless_late_init)]
+#![allow(clippy::redundant_field_names)]
+#![allow(clippy::collapsible_if)]
+#![allow(clippy::collapsible_else_if)]
+#![allow(clippy::needless_pub_self)]
+#![allow(clippy::if_same_then_else)]
+
 #![deny(arithmetic_overflow)]
 #![deny(overflowing_literals)]
 
@@ -38,7 +46,10 @@ pub(crate) mod platform;
 pub(crate) mod types;
 
 pub use interface::{Date};
+pub use interface::{DateTime};
+pub use interface::{Instant};
 pub use interface::{Time};
+pub use interface::{DateRange, Step};
 #[cfg(any(
     feature = "platform_specific_functions_darwin",
     feature = "platform_specific_functions_unix",
@@ -47,9 +58,24 @@ pub use interface::{Time};
 pub use interface::{Uptime};
 
 pub use crate::types::{
+    error::{Error},
     data::zone::{Sign, Zone},
+    data::month_end::{MonthEnd},
+    data::period::{Period},
+    data::duration::{Duration},
+    data::interval::{Interval},
+    data::adjustment::{Adjustment},
+    data::business_calendar::{BusinessCalendar},
+    data::holiday::{Holiday, HolidayDate, HolidayRule, HolidayRules, Observance},
+    format::{
+        iso8601::{Representation, Notation},
+        rfc3339::{LeapSecond, Fraction}
+    },
     planets::earth::calendar::{
         view::{CalendarView},
+        system::{CalendarSystem, JulianCalendar, GregorianCalendar, SolarCalendar},
+        cutover::{Cutover},
+        easter::{Computus, Feast},
         traits::{
             converter::{Julian, Gregorian, Solar},
             day_of_week::{Xavetar, RataDie, Sakamoto}
@@ -67,6 +93,7 @@ pub mod constants {
             constants::{
                 week::{DAYS_IN_WEEK},
                 months::{MONTHS_IN_YEAR},
+                seconds::{NANOSECONDS_IN_SECOND},
             }
         }
     };
@@ -76,7 +103,8 @@ pub mod functions {
     pub use crate::types::{
         planets::earth::calendar::{
             functions::{
-                is_leap_year
+                is_leap_year, sum_leap_years, days_in_month,
+                weeks_in_year, us_weeks_in_year
             }
         }
     };
@@ -31,7 +31,7 @@ use super::c::{
     mach_timebase_info_data_t, mach_timebase_info, mach_absolute_time
 };
 
-pub fn uptime() -> u64 {
+pub fn uptime() -> (u64, u32) {
     let mut timebase_info: mach_timebase_info_data_t = mach_timebase_info_data_t {
         numer: 0, denom: 0
     };
@@ -40,5 +40,7 @@ pub fn uptime() -> u64 {
         panic!("Error calling mach_timebase_info.");
     }
 
-    return ((unsafe { mach_absolute_time() } * timebase_info.numer as u64) / timebase_info.denom as u64) / NSEC_PER_SEC as u64;
+    let nanoseconds: u64 = (unsafe { mach_absolute_time() } * timebase_info.numer as u64) / timebase_info.denom as u64;
+
+    return (nanoseconds / NSEC_PER_SEC as u64, (nanoseconds % NSEC_PER_SEC as u64) as u32);
 }
\ No newline at end of file
@@ -28,12 +28,12 @@
 
 use libc::{clock_gettime, timespec, CLOCK_MONOTONIC_RAW};
 
-pub fn uptime() -> u64 {
+pub fn uptime() -> (u64, u32) {
     let mut ts: timespec = timespec { tv_sec: 0, tv_nsec: 0 };
 
     if unsafe { clock_gettime(CLOCK_MONOTONIC_RAW, &mut ts) } != 0 {
         panic!("Error calling clock_gettime");
     }
 
-    return ts.tv_sec as u64;
+    return (ts.tv_sec as u64, ts.tv_nsec as u32);
 }
\ No newline at end of file
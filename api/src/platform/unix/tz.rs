@@ -48,7 +48,7 @@ pub fn local_timezone() -> Zone {
 
     let (epoch_seconds, mut time_struct): (time_t, tm) = unsafe { (time(std::ptr::null_mut()), std::mem::zeroed::<tm>()) };
 
-    if unsafe { localtime_r(&epoch_seconds, &mut time_struct) } == std::ptr::null_mut() {
+    if unsafe { localtime_r(&epoch_seconds, &mut time_struct) }.is_null() {
         panic!("[ERROR]: Pointer is NULL (timezone)!")
     }
 
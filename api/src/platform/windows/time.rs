@@ -33,7 +33,7 @@ use winapi::{
     }
 };
 
-pub fn uptime() -> u64 {
+pub fn uptime() -> (u64, u32) {
     let (mut counter, mut frequency): (LARGE_INTEGER, LARGE_INTEGER) = unsafe { (std::mem::zeroed::<LARGE_INTEGER>(), std::mem::zeroed::<LARGE_INTEGER>()) };
 
     if unsafe { QueryPerformanceFrequency(&mut frequency) } == 0 {
@@ -44,5 +44,7 @@ pub fn uptime() -> u64 {
         panic!("Failed to get performance counter value!");
     }
 
-    return (unsafe { *counter.QuadPart() / *frequency.QuadPart() }) as u64;
+    let (counter, frequency): (u128, u128) = unsafe { (*counter.QuadPart() as u128, *frequency.QuadPart() as u128) };
+
+    return ((counter / frequency) as u64, (((counter % frequency) * 1_000_000_000_u128) / frequency) as u32);
 }
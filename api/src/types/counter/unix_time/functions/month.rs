@@ -44,6 +44,26 @@ use crate::types::{
 };
 
 pub fn month_from_days(view: CalendarView, year: u64, days: &mut u16) -> Months {
+    // В году перехода григорианские дни начинаются с порядкового номера первого григорианского дня.
+    if let CalendarView::Historical(cutover) = view {
+        if year > cutover.year || (year == cutover.year && *days >= cutover.ordinal()) {
+            return month_from_days(CalendarView::Gregorian, year, days);
+        } else {
+            return month_from_days(CalendarView::Julian, year, days);
+        }
+    }
+
+    if let CalendarView::Custom(system) = view {
+        let (leap_year, mut month): (bool, u8) = (system.is_leap_year(year), 1_u8);
+
+        while month < system.months_in_year() && *days > system.month_days(month, leap_year) as u16 {
+            *days -= system.month_days(month, leap_year) as u16;
+            month += 1_u8;
+        }
+
+        return Months::from(month);
+    }
+
     let months: &[u8; MONTHS_IN_YEAR as usize];
 
     if !is_leap_year(view, year) {
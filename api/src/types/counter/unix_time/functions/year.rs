@@ -30,24 +30,33 @@ use crate::types::{
     planets::earth::calendar::{
         view::{CalendarView},
         constants::{
-            year::{BASE_DAYS_YEAR, LEAP_DAYS_YEAR}
+            days::{JULIAN_BCE_DAYS_FIRST_YEAR}
         },
         functions::{is_leap_year, days_from_presentation_date}
     }
 };
 
 pub fn year_from_presentation_days(view: CalendarView, presentation_days: u128) -> (u64, u16) {
+    if let CalendarView::Historical(cutover) = view {
+        if presentation_days >= cutover.era_days() + JULIAN_BCE_DAYS_FIRST_YEAR {
+            return year_from_presentation_days(CalendarView::Gregorian, presentation_days - JULIAN_BCE_DAYS_FIRST_YEAR);
+        } else {
+            return year_from_presentation_days(CalendarView::Julian, presentation_days);
+        }
+    }
+
+    let (base_days_year, leap_days_year): (u16, u16) = (view.system().year_days(false), view.system().year_days(true));
 
     let (base_div, leap_div): (u128, u128)
         =
         (
-            (presentation_days - (presentation_days % BASE_DAYS_YEAR as u128)) / BASE_DAYS_YEAR as u128,
-            (presentation_days - (presentation_days % LEAP_DAYS_YEAR as u128)) / LEAP_DAYS_YEAR as u128
+            (presentation_days - (presentation_days % base_days_year as u128)) / base_days_year as u128,
+            (presentation_days - (presentation_days % leap_days_year as u128)) / leap_days_year as u128
         );
 
     let (upper_limit_year, lower_limit_year): (u128, u128);
 
-    if LEAP_DAYS_YEAR > BASE_DAYS_YEAR {
+    if leap_days_year > base_days_year {
         (upper_limit_year, lower_limit_year) = (base_div, leap_div);
     } else {
         (upper_limit_year, lower_limit_year) = (leap_div, base_div);
@@ -89,10 +98,10 @@ pub fn year_from_presentation_days(view: CalendarView, presentation_days: u128)
         let delta_days: u128 = presentation_days - potential_era_days;
 
         if delta_days > 1_000_000_u128 {
-            if LEAP_DAYS_YEAR > BASE_DAYS_YEAR {
-                potential_year += (delta_days / LEAP_DAYS_YEAR as u128) as u64;
+            if leap_days_year > base_days_year {
+                potential_year += (delta_days / leap_days_year as u128) as u64;
             } else {
-                potential_year += (delta_days / BASE_DAYS_YEAR as u128) as u64;
+                potential_year += (delta_days / base_days_year as u128) as u64;
             }
 
             potential_era_days = days_from_presentation_date(view, potential_year, 0, 0);
@@ -106,11 +115,11 @@ pub fn year_from_presentation_days(view: CalendarView, presentation_days: u128)
     loop {
         let leap_year: bool = is_leap_year(view, potential_year);
 
-        if leap_year && unaccounted_days > LEAP_DAYS_YEAR as u32 {
-            unaccounted_days -= LEAP_DAYS_YEAR as u32;
+        if leap_year && unaccounted_days > leap_days_year as u32 {
+            unaccounted_days -= leap_days_year as u32;
             potential_year += 1_u64;
-        } else if !leap_year && unaccounted_days > BASE_DAYS_YEAR as u32 {
-            unaccounted_days -= BASE_DAYS_YEAR as u32;
+        } else if !leap_year && unaccounted_days > base_days_year as u32 {
+            unaccounted_days -= base_days_year as u32;
             potential_year += 1_u64;
         } else {
             break;
@@ -124,10 +133,17 @@ pub fn year_from_presentation_days(view: CalendarView, presentation_days: u128)
 mod tests {
     use super::{
         CalendarView,
-        BASE_DAYS_YEAR, LEAP_DAYS_YEAR,
         is_leap_year, days_from_presentation_date, year_from_presentation_days
     };
 
+    use crate::types::{
+        planets::earth::calendar::{
+            constants::{
+                year::{BASE_DAYS_YEAR, LEAP_DAYS_YEAR}
+            },
+        }
+    };
+
     #[test]
     fn test_year_from_presentation_days_const() {
         // Проверка для небольших значений presentation_days
@@ -0,0 +1,322 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use std::{
+    collections::{BTreeSet}
+};
+
+use super::{
+    date::{Date},
+    adjustment::{Adjustment}
+};
+
+use crate::types::{
+    error::{Error},
+    planets::{
+        earth::{
+            calendar::{
+                constants::{
+                    week::{Week, DAYS_IN_WEEK}
+                },
+                traits::{
+                    day_of_week::{RataDie}
+                }
+            }
+        },
+    },
+};
+
+// Рабочие дни: маска выходных дней недели и праздники. Праздники хранятся днями эры, поэтому совпадают в любом календаре.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusinessCalendar {
+    weekend: u8,
+    holidays: BTreeSet<u128>
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> BusinessCalendar {
+        return BusinessCalendar { weekend: week_bit(Week::Saturday) | week_bit(Week::Sunday), holidays: BTreeSet::new() };
+    }
+}
+
+const fn week_bit(week_day: Week) -> u8 {
+    return 1_u8 << (week_day.index() - 1_u8);
+}
+
+// Первый день эры (0001-01-01 григорианского календаря) - понедельник.
+fn era_week_day(era_days: u128) -> Week {
+    return Week::Monday.next_nth(era_days - 1_u128);
+}
+
+impl BusinessCalendar {
+    pub fn new(weekend: &[Week]) -> Result<BusinessCalendar, Error> {
+        let mask: u8 = weekend.iter().fold(0_u8, |mask, week_day| mask | week_bit(*week_day));
+
+        if mask.count_ones() == DAYS_IN_WEEK as u32 {
+            return Err(Error::InvalidWeekend);
+        }
+
+        return Ok(BusinessCalendar { weekend: mask, holidays: BTreeSet::new() });
+    }
+
+    pub fn with_holidays<I: IntoIterator<Item = Date>>(mut self, holidays: I) -> BusinessCalendar {
+        self.holidays.extend(holidays.into_iter().map(|date| date.era_days));
+
+        return self;
+    }
+
+    pub fn add_holiday(&mut self, date: Date) -> bool {
+        return self.holidays.insert(date.era_days);
+    }
+
+    pub fn remove_holiday(&mut self, date: &Date) -> bool {
+        return self.holidays.remove(&date.era_days);
+    }
+
+    pub const fn is_weekend(&self, week_day: Week) -> bool {
+        return self.weekend & week_bit(week_day) != 0_u8;
+    }
+
+    pub fn is_holiday(&self, date: &Date) -> bool {
+        return self.holidays.contains(&date.era_days);
+    }
+
+    pub fn is_business_day(&self, date: &Date) -> bool {
+        return !self.is_weekend(<Date as RataDie>::week_day(date)) && !self.is_holiday(date);
+    }
+
+    pub fn next_business_day(&self, date: &Date) -> Result<Date, Error> {
+        return self.add_business_days(date, 1_i128);
+    }
+
+    pub fn previous_business_day(&self, date: &Date) -> Result<Date, Error> {
+        return self.add_business_days(date, -1_i128);
+    }
+
+    pub fn adjust(&self, date: &Date, convention: Adjustment) -> Result<Date, Error> {
+        if convention == Adjustment::Unadjusted || self.is_business_day(date) {
+            return Ok(*date);
+        }
+
+        let same_month = |other: &Date| -> bool { return (other.year, other.month) == (date.year, date.month); };
+
+        // Второй день считается только при переходе в другой месяц или за границу эры.
+        return match convention {
+            Adjustment::Following | Adjustment::Unadjusted => self.next_business_day(date),
+            Adjustment::ModifiedFollowing => match self.next_business_day(date) {
+                Ok(following) if same_month(&following) => Ok(following),
+                _ => self.previous_business_day(date)
+            },
+            Adjustment::Preceding => self.previous_business_day(date),
+            Adjustment::ModifiedPreceding => match self.previous_business_day(date) {
+                Ok(preceding) if same_month(&preceding) => Ok(preceding),
+                _ => self.next_business_day(date)
+            }
+        };
+    }
+
+    // Сдвиг на days рабочих дней (назад при отрицательном), сама дата не считается: от субботы +1 - понедельник.
+    pub fn add_business_days(&self, date: &Date, days: i128) -> Result<Date, Error> {
+        let week_days: u128 = (DAYS_IN_WEEK as u32 - self.weekend.count_ones()) as u128;
+
+        let (mut date, mut remaining, forward): (Date, u128, bool) = (*date, days.unsigned_abs(), days > 0_i128);
+
+        while remaining > 0_u128 {
+            // Целые недели содержат каждый день недели поровну, из них вычитаются только праздники.
+            let weeks: u128 = (remaining - 1_u128) / week_days;
+
+            if weeks > 0_u128 {
+                let shift: u128 = weeks.checked_mul(DAYS_IN_WEEK as u128).ok_or(Error::Overflow)?;
+
+                let next: Date = if forward { date.checked_add_days(shift)? } else { date.checked_sub_days(shift)? };
+
+                let holidays: u128 = if forward {
+                    self.business_holidays(date.era_days + 1_u128, next.era_days + 1_u128)
+                } else {
+                    self.business_holidays(next.era_days, date.era_days)
+                };
+
+                (date, remaining) = (next, remaining + holidays - weeks * week_days);
+
+                continue;
+            }
+
+            date = if forward { date.checked_add_days(1_u128)? } else { date.checked_sub_days(1_u128)? };
+
+            if self.is_business_day(&date) {
+                remaining -= 1_u128;
+            }
+        }
+
+        return Ok(date);
+    }
+
+    // Рабочие дни от start включительно до end не включительно, отрицательное число - если end раньше start.
+    pub fn business_days_between(&self, start: &Date, end: &Date) -> i128 {
+        if end.era_days < start.era_days {
+            return -self.business_days_between(end, start);
+        }
+
+        let (from, to): (u128, u128) = (start.era_days, end.era_days);
+
+        let weeks: u128 = (to - from) / DAYS_IN_WEEK as u128;
+
+        let mut days: u128 = weeks * (DAYS_IN_WEEK as u32 - self.weekend.count_ones()) as u128;
+
+        for era_days in from + weeks * DAYS_IN_WEEK as u128..to {
+            days += !self.is_weekend(era_week_day(era_days)) as u128;
+        }
+
+        return (days - self.business_holidays(from, to)) as i128;
+    }
+
+    // Праздники в днях эры [from, to), выпавшие не на выходные.
+    fn business_holidays(&self, from: u128, to: u128) -> u128 {
+        return self.holidays.range(from..to).filter(|era_days| !self.is_weekend(era_week_day(**era_days))).count() as u128;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Adjustment, BusinessCalendar, Date, Error, Week
+    };
+
+    use crate::types::{
+        data::{
+            zone::{Zone}
+        },
+        planets::{
+            earth::{
+                calendar::{
+                    view::{CalendarView}
+                }
+            }
+        }
+    };
+
+    fn date(month: u8, day: u8) -> Date {
+        return Date::new(CalendarView::Gregorian, 2024, month, day, Zone::default()).unwrap();
+    }
+
+    fn day_of(date: Result<Date, Error>) -> Result<(u8, u8), Error> {
+        return date.map(|date| (date.month().index(), date.day()));
+    }
+
+    #[test]
+    fn test_add_business_days() {
+        let calendar: BusinessCalendar = BusinessCalendar::default();
+
+        // 1 марта 2024 года - пятница.
+        for (start, days, end) in [((3, 1), 1, (3, 4)), ((3, 1), 10, (3, 15)), ((3, 1), -1, (2, 29)), ((3, 2), 1, (3, 4)), ((3, 2), -1, (3, 1)), ((3, 2), 0, (3, 2))] {
+            assert_eq!(day_of(calendar.add_business_days(&date(start.0, start.1), days)), Ok(end), "{start:?} {days}");
+        }
+
+        let gulf: BusinessCalendar = BusinessCalendar::new(&[Week::Friday, Week::Saturday]).unwrap();
+
+        assert_eq!(day_of(gulf.next_business_day(&date(2, 29))), Ok((3, 3)));
+        assert_eq!(day_of(gulf.previous_business_day(&date(3, 3))), Ok((2, 29)));
+
+        // Праздник задан в юлианском календаре: 20 февраля = 4 марта по григорианскому.
+        let holidays: BusinessCalendar = BusinessCalendar::default().with_holidays([Date::new(CalendarView::Julian, 2024, 2, 20, Zone::default()).unwrap()]);
+
+        assert!(holidays.is_holiday(&date(3, 4)) && !holidays.is_business_day(&date(3, 4)));
+        assert_eq!(day_of(holidays.next_business_day(&date(3, 1))), Ok((3, 5)));
+        assert_eq!(day_of(holidays.add_business_days(&date(3, 1), 10)), Ok((3, 18)));
+
+        assert_eq!(BusinessCalendar::new(&[Week::Monday, Week::Tuesday, Week::Wednesday, Week::Thursday, Week::Friday, Week::Saturday, Week::Sunday]), Err(Error::InvalidWeekend));
+        assert_eq!(BusinessCalendar::default().previous_business_day(&Date::new(CalendarView::Gregorian, 1, 1, 1, Zone::default()).unwrap()), Err(Error::OutsideEra(CalendarView::Gregorian)));
+    }
+
+    #[test]
+    fn test_adjust() {
+        let calendar: BusinessCalendar = BusinessCalendar::default();
+
+        // 31 марта 2024 года - воскресенье, 1 июня - суббота.
+        for (start, convention, end) in [
+            ((3, 31), Adjustment::Following, (4, 1)),
+            ((3, 31), Adjustment::ModifiedFollowing, (3, 29)),
+            ((3, 31), Adjustment::Preceding, (3, 29)),
+            ((6, 1), Adjustment::Preceding, (5, 31)),
+            ((6, 1), Adjustment::ModifiedPreceding, (6, 3)),
+            ((6, 1), Adjustment::Unadjusted, (6, 1)),
+            ((6, 3), Adjustment::Preceding, (6, 3))
+        ] {
+            assert_eq!(day_of(calendar.adjust(&date(start.0, start.1), convention)), Ok(end), "{start:?} {convention:?}");
+        }
+
+        // 1 января 1 года - понедельник, предыдущего рабочего дня нет.
+        let first: Date = Date::new(CalendarView::Gregorian, 1, 1, 1, Zone::default()).unwrap();
+        let holiday: BusinessCalendar = BusinessCalendar::default().with_holidays([first]);
+
+        for (convention, end) in [
+            (Adjustment::Following, Ok((1, 2))),
+            (Adjustment::ModifiedFollowing, Ok((1, 2))),
+            (Adjustment::ModifiedPreceding, Ok((1, 2))),
+            (Adjustment::Preceding, Err(Error::OutsideEra(CalendarView::Gregorian)))
+        ] {
+            assert_eq!(day_of(holiday.adjust(&first, convention)), end, "{convention:?}");
+        }
+    }
+
+    #[test]
+    fn test_business_days_sequence() {
+        let mut holidays: Vec<Date> = Vec::new();
+        let mut holiday: Date = date(1, 1);
+
+        while holiday.year() == 2024 {
+            holidays.push(holiday);
+            holiday = holiday.checked_add_days(11).unwrap();
+        }
+
+        for calendar in [
+            BusinessCalendar::default(),
+            BusinessCalendar::new(&[Week::Friday, Week::Saturday]).unwrap().with_holidays(holidays.iter().copied()),
+            BusinessCalendar::new(&[Week::Sunday]).unwrap().with_holidays(holidays.iter().copied()),
+            BusinessCalendar::new(&[]).unwrap().with_holidays(holidays.iter().copied())
+        ] {
+            // Рабочие дни 2023 - 2025 годов, перебором по одному дню.
+            let first: Date = Date::new(CalendarView::Gregorian, 2023, 1, 1, Zone::default()).unwrap();
+
+            let business: Vec<Date> = (0_u128..3_u128 * 365_u128)
+                .map(|days| first.checked_add_days(days).unwrap())
+                .filter(|date| calendar.is_business_day(date))
+                .collect();
+
+            for (index, start) in business.iter().enumerate().skip(200).step_by(17).take(20) {
+                for days in [1_usize, 2, 5, 6, 7, 13, 50] {
+                    assert_eq!(calendar.add_business_days(start, days as i128), Ok(business[index + days]), "{start:?} {days}");
+                    assert_eq!(calendar.add_business_days(start, -(days as i128)), Ok(business[index - days]), "{start:?} -{days}");
+                    assert_eq!(calendar.business_days_between(start, &business[index + days]), days as i128);
+                    assert_eq!(calendar.business_days_between(&business[index + days], start), -(days as i128));
+                    assert_eq!(calendar.business_days_between(start, &business[index + days].checked_add_days(1).unwrap()), days as i128 + 1);
+                }
+            }
+        }
+    }
+}
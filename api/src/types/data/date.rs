@@ -26,21 +26,27 @@
  * THE SOFTWARE.
  */
 
+use std::{
+    cmp::{Ordering},
+    hash::{Hash, Hasher}
+};
+
 use super::{
-    zone::{
-        Sign, Zone
-    }
+    zone::{Zone}
 };
 
 use crate::types::{
+    error::{Error},
     planets::{
         earth::{
             calendar::{
                 view::{CalendarView},
                 constants::{
-                    seconds::{SECONDS_IN_DAY},
-                    days::{JULIAN_BCE_DAYS_FIRST_YEAR},
-                }
+                    seconds::{SECONDS_IN_DAY, NANOSECONDS_IN_SECOND},
+                },
+                functions::{
+                    last_presentation_day
+                },
             }
         },
     },
@@ -56,61 +62,105 @@ use crate::types::{
     },
 };
 
-type UNIX_EPOCH = (u32, u128);
+type UNIX_EPOCH = (u32, i128);
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+// Сравнение и хеш по абсолютному моменту (instant), календарь и часовой пояс не учитываются, по полям: same_civil_fields().
+#[derive(Debug, Clone, Copy)]
 pub struct Date {
-    pub day: u8,
-    pub month: u8,
-    pub year: u64,
-    pub time_zone: Zone,
-    pub unix_time: u128,
-    pub era_days: u128,
-    pub view: CalendarView
+    pub(crate) day: u8,
+    pub(crate) month: u8,
+    pub(crate) year: u64,
+    pub(crate) time_zone: Zone,
+    // Местные секунды от начала unix эпохи (часовой пояс уже учтён), момент UTC возвращает instant().
+    pub(crate) unix_time: i128,
+    pub(crate) nanoseconds: u32,
+    pub(crate) era_days: u128,
+    pub(crate) view: CalendarView
+}
+
+impl Default for Date {
+    fn default() -> Date {
+        return Date::of(CalendarView::default(), 0_i128, 0_u32, Zone::default());
+    }
+}
+
+impl PartialEq for Date {
+    fn eq(&self, other: &Date) -> bool {
+        return self.instant() == other.instant();
+    }
+}
+
+impl Eq for Date {}
+
+impl PartialOrd for Date {
+    fn partial_cmp(&self, other: &Date) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for Date {
+    fn cmp(&self, other: &Date) -> Ordering {
+        return self.instant().cmp(&other.instant());
+    }
+}
+
+impl Hash for Date {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instant().hash(state);
+    }
 }
 
 impl Date {
-    pub(crate) fn of(view: CalendarView, mut unix_time: u128, time_zone: Zone, zone_in_unix: bool) -> Date {
-        if !zone_in_unix {
-            let time_zone_seconds: u128 = time_zone.to_seconds() as u128;
-
-            if unix_time < time_zone_seconds && time_zone.sign == Sign::Signed {
-                panic!("[OVERFLOW]: Overflow type, unix time - time zone < zero!")
-            } else if unix_time > u128::MAX - time_zone_seconds && time_zone.sign == Sign::Unsigned {
-                panic!("[OVERFLOW]: Overflow type, unix time + time zone > type!")
-            }
+    pub(crate) fn of(view: CalendarView, local_seconds: i128, nanoseconds: u32, time_zone: Zone) -> Date {
+        match Date::try_of(view, local_seconds, nanoseconds, time_zone) {
+            Ok(date) => return date,
+            Err(error) => panic!("{}", error)
+        }
+    }
 
-            if time_zone.sign == Sign::Signed {
-                unix_time -= time_zone_seconds;
-            } else if time_zone.sign == Sign::Unsigned {
-                unix_time += time_zone_seconds;
-            }
+    pub(crate) fn try_of(view: CalendarView, local_seconds: i128, nanoseconds: u32, time_zone: Zone) -> Result<Date, Error> {
+        if nanoseconds >= NANOSECONDS_IN_SECOND {
+            return Err(Error::InvalidNanoseconds(nanoseconds));
         }
 
         let mut days: u16;
-        let mut date: Date = Date::default();
+        let mut date: Date = Date {
+            day: 0_u8,
+            month: 0_u8,
+            year: 0_u64,
+            time_zone: Zone::default(),
+            unix_time: 0_i128,
+            nanoseconds: 0_u32,
+            era_days: 0_u128,
+            view: CalendarView::default()
+        };
 
-        let (_day_seconds, mut presentation_days, ): UNIX_EPOCH
+        let (_day_seconds, unix_days): UNIX_EPOCH
         =
-        ((unix_time % SECONDS_IN_DAY) as u32, (unix_time - (unix_time % SECONDS_IN_DAY)) / SECONDS_IN_DAY);
+        (local_seconds.rem_euclid(SECONDS_IN_DAY as i128) as u32, local_seconds.div_euclid(SECONDS_IN_DAY as i128));
+
+        // Дни до 1970 года отрицательны, но не могут уходить за начало эры.
+        if unix_days < -(UNIX_TIME_START_AFTER_DAY as i128) {
+            return Err(Error::OutsideEra(view));
+        }
+
+        let mut presentation_days: u128 = (unix_days + (UNIX_TIME_START_AFTER_DAY + 1_u128) as i128) as u128;
 
-        presentation_days += UNIX_TIME_START_AFTER_DAY + 1_u128;
+        presentation_days += view.system().epoch_offset();
 
-        if view == CalendarView::Julian {
-            presentation_days += JULIAN_BCE_DAYS_FIRST_YEAR;
+        if presentation_days > last_presentation_day(view) {
+            return Err(Error::Overflow);
         }
 
         (date.year, days) = year_from_presentation_days(view, presentation_days);
         date.month = month_from_days(view, date.year, &mut days).index();
 
-        if view == CalendarView::Julian {
-            presentation_days -= JULIAN_BCE_DAYS_FIRST_YEAR;
-        }
+        presentation_days -= view.system().epoch_offset();
 
-        (date.day, date.time_zone, date.unix_time, date.era_days, date.view)
+        (date.day, date.time_zone, date.unix_time, date.nanoseconds, date.era_days, date.view)
         =
-        (days as u8, time_zone, unix_time, presentation_days, view);
+        (days as u8, time_zone, local_seconds, nanoseconds, presentation_days, view);
 
-        return date;
+        return Ok(date);
     }
 }
\ No newline at end of file
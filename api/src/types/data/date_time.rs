@@ -0,0 +1,86 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use std::{
+    cmp::{Ordering},
+    hash::{Hash, Hasher}
+};
+
+use super::{
+    date::{Date},
+    time::{Time}
+};
+
+// Сравнение и хеш по абсолютному моменту (instant), как у Date, по полям: same_civil_fields().
+#[derive(Debug, Clone, Copy)]
+pub struct DateTime {
+    pub(crate) date: Date,
+    pub(crate) time: Time
+}
+
+impl PartialEq for DateTime {
+    fn eq(&self, other: &DateTime) -> bool {
+        return self.instant() == other.instant();
+    }
+}
+
+impl Eq for DateTime {}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &DateTime) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &DateTime) -> Ordering {
+        return self.instant().cmp(&other.instant());
+    }
+}
+
+impl Hash for DateTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instant().hash(state);
+    }
+}
+
+impl Default for DateTime {
+    fn default() -> DateTime {
+        return DateTime::of(Date::default());
+    }
+}
+
+impl DateTime {
+    // Время внутри дня берётся из того же unix time, что и дата, поэтому они не могут разойтись.
+    pub(crate) const fn of(date: Date) -> DateTime {
+        return DateTime {
+            date: date,
+            time: Time::from_seconds(date.unix_time, date.nanoseconds, date.time_zone)
+        };
+    }
+}
@@ -0,0 +1,402 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use std::{
+    collections::{BTreeSet}
+};
+
+use super::{
+    date::{Date},
+    zone::{Zone}
+};
+
+use crate::types::{
+    error::{Error},
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                easter::{Computus},
+                constants::{
+                    week::{Week, DAYS_IN_WEEK}
+                },
+                traits::{
+                    day_of_week::{RataDie}
+                }
+            }
+        },
+    },
+};
+
+// День праздника в году календаря правила.
+#[derive(Debug, Clone, Copy)]
+pub enum HolidayDate {
+    // Месяц и день.
+    Fixed(u8, u8),
+    // Месяц, день недели и его номер в месяце (отрицательный - с конца месяца).
+    NthWeekday(u8, Week, i8),
+    // Первый день недели строго после (до) месяца и дня.
+    WeekdayAfter(u8, u8, Week),
+    WeekdayBefore(u8, u8, Week),
+    // Дни от Пасхи.
+    Easter(Computus, i16),
+    Custom(fn(CalendarView, u64, Zone) -> Result<Date, Error>)
+}
+
+// Перенос праздника, выпавшего на субботу или воскресенье.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum Observance {
+    #[default]
+    Actual,
+    // Суббота - на пятницу, воскресенье - на понедельник.
+    NearestWeekday,
+    // Суббота и воскресенье - на понедельник.
+    NextMonday,
+    // На ближайший будний день, не занятый другим праздником.
+    Substitute,
+    Custom(fn(Date) -> Result<Date, Error>)
+}
+
+const fn is_weekend(week_day: Week) -> bool {
+    return matches!(week_day, Week::Saturday | Week::Sunday);
+}
+
+impl Observance {
+    // Substitute без других праздников переносит на понедельник, занятые дни учитывает HolidayRules.
+    pub fn apply(&self, date: &Date) -> Result<Date, Error> {
+        let week_day: Week = <Date as RataDie>::week_day(date);
+
+        return match self {
+            Observance::Actual => Ok(*date),
+            Observance::NearestWeekday => match week_day {
+                Week::Saturday => date.checked_sub_days(1_u128),
+                Week::Sunday => date.checked_add_days(1_u128),
+                _ => Ok(*date)
+            },
+            Observance::NextMonday | Observance::Substitute => {
+                if is_weekend(week_day) {
+                    date.on_or_after(Week::Monday)
+                } else {
+                    Ok(*date)
+                }
+            },
+            Observance::Custom(observe) => observe(*date)
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct HolidayRule {
+    pub name: &'static str,
+    pub date: HolidayDate,
+    pub observance: Observance,
+    // Календарь, в котором задан день праздника.
+    pub view: CalendarView,
+    // Первый год календаря правила, с которого праздник отмечается.
+    pub since: u64
+}
+
+impl HolidayRule {
+    pub const fn new(name: &'static str, date: HolidayDate) -> HolidayRule {
+        return HolidayRule { name: name, date: date, observance: Observance::Actual, view: CalendarView::Gregorian, since: 1_u64 };
+    }
+
+    pub const fn observed(self, observance: Observance) -> HolidayRule {
+        return HolidayRule { observance: observance, ..self };
+    }
+
+    pub const fn in_view(self, view: CalendarView) -> HolidayRule {
+        return HolidayRule { view: view, ..self };
+    }
+
+    pub const fn since(self, year: u64) -> HolidayRule {
+        return HolidayRule { since: year, ..self };
+    }
+
+    // День праздника без переноса, год - в календаре правила.
+    pub fn actual_date(&self, year: u64, time_zone: Zone) -> Result<Date, Error> {
+        return match self.date {
+            HolidayDate::Fixed(month, day) => Date::new(self.view, year, month, day, time_zone),
+            HolidayDate::NthWeekday(month, week_day, n) => Date::nth_weekday(self.view, year, month, week_day, n, time_zone),
+            HolidayDate::WeekdayAfter(month, day, week_day) => Date::new(self.view, year, month, day, time_zone)?.next_weekday(week_day),
+            HolidayDate::WeekdayBefore(month, day, week_day) => Date::new(self.view, year, month, day, time_zone)?.previous_weekday(week_day),
+            HolidayDate::Easter(computus, days) => {
+                let easter: Date = Date::easter(computus, self.view, year, time_zone)?;
+
+                if days < 0_i16 {
+                    easter.checked_sub_days(days.unsigned_abs() as u128)
+                } else {
+                    easter.checked_add_days(days as u128)
+                }
+            },
+            HolidayDate::Custom(date) => date(self.view, year, time_zone)
+        };
+    }
+
+    // Проверка правила без года по самому длинному варианту месяца: правило, прошедшее её, существует хотя бы в каком-то году.
+    fn validate(&self) -> Result<(), Error> {
+        let (month, day, n): (u8, u8, i8) = match self.date {
+            HolidayDate::Fixed(month, day) | HolidayDate::WeekdayAfter(month, day, _) | HolidayDate::WeekdayBefore(month, day, _) => (month, day, 1_i8),
+            HolidayDate::NthWeekday(month, _, n) => (month, 1_u8, n),
+            HolidayDate::Easter(..) | HolidayDate::Custom(_) => return Ok(())
+        };
+
+        if !(1_u8..=self.view.system().months_in_year()).contains(&month) {
+            return Err(Error::InvalidMonth(month));
+        }
+
+        let month_days: u8 = self.view.system().month_days(month, true);
+
+        if day == 0_u8 || day > month_days {
+            return Err(Error::InvalidDay(day));
+        } else if n == 0_i8 || n.unsigned_abs() > (month_days + DAYS_IN_WEEK - 1_u8) / DAYS_IN_WEEK {
+            return Err(Error::InvalidOccurrence(n));
+        }
+
+        return Ok(());
+    }
+}
+
+// Праздник: день, когда он отмечается, и день по правилу до переноса.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Holiday {
+    pub name: &'static str,
+    pub date: Date,
+    pub actual: Date
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HolidayRules {
+    rules: Vec<HolidayRule>
+}
+
+impl HolidayRules {
+    pub fn new<I: IntoIterator<Item = HolidayRule>>(rules: I) -> HolidayRules {
+        return HolidayRules { rules: rules.into_iter().collect() };
+    }
+
+    pub fn push(&mut self, rule: HolidayRule) {
+        self.rules.push(rule);
+    }
+
+    pub fn rules(&self) -> &[HolidayRule] {
+        return &self.rules;
+    }
+
+    // Праздники, которые отмечаются в году year календаря view, по дням.
+    pub fn holidays_in_year(&self, view: CalendarView, year: u64, time_zone: Zone) -> Result<Vec<Holiday>, Error> {
+        // Соседние годы: перенос и календарь правила сдвигают праздник через границу года.
+        let years: Vec<u64> = [year.checked_sub(1_u64), Some(year), year.checked_add(1_u64)].into_iter().flatten().filter(|year| *year > 0_u64).collect();
+
+        let mut actual: Vec<(Date, &HolidayRule)> = Vec::new();
+
+        for rule in self.rules.iter() {
+            rule.validate()?;
+
+            for rule_year in years.iter().filter(|rule_year| **rule_year >= rule.since) {
+                match rule.actual_date(*rule_year, time_zone) {
+                    Ok(date) => actual.push((date, rule)),
+                    // Дня нет в этом году (29 февраля, пятый понедельник, день, пропущенный при переходе) или он вне эры.
+                    Err(Error::InvalidDay(_) | Error::InvalidOccurrence(_) | Error::SkippedDay(_) | Error::OutsideEra(_) | Error::Overflow) => {},
+                    // Ошибка пользовательского правила важна только для запрошенного года.
+                    Err(_) if *rule_year != year => {},
+                    Err(error) => return Err(error)
+                }
+            }
+        }
+
+        actual.sort_by_key(|(date, _)| date.era_days);
+
+        let (mut holidays, mut taken): (Vec<Holiday>, BTreeSet<u128>) = (Vec::new(), BTreeSet::new());
+
+        let substitute = |date: &Date, rule: &HolidayRule| -> bool {
+            return matches!(rule.observance, Observance::Substitute) && is_weekend(<Date as RataDie>::week_day(date));
+        };
+
+        for (date, rule) in actual.iter().filter(|(date, rule)| !substitute(date, rule)) {
+            let observed: Date = rule.observance.apply(date)?;
+
+            taken.insert(observed.era_days);
+            holidays.push(Holiday { name: rule.name, date: observed, actual: *date });
+        }
+
+        // Замещающие дни раздаются по порядку праздников после всех остальных.
+        for (date, rule) in actual.iter().filter(|(date, rule)| substitute(date, rule)) {
+            let mut observed: Date = date.on_or_after(Week::Monday)?;
+
+            while taken.contains(&observed.era_days) || is_weekend(<Date as RataDie>::week_day(&observed)) {
+                observed = observed.checked_add_days(1_u128)?;
+            }
+
+            taken.insert(observed.era_days);
+            holidays.push(Holiday { name: rule.name, date: observed, actual: *date });
+        }
+
+        let mut in_year: Vec<Holiday> = Vec::new();
+
+        for holiday in holidays {
+            let date: Date = holiday.date.to_view(view)?;
+
+            if date.year == year {
+                in_year.push(Holiday { name: holiday.name, date: date, actual: holiday.actual.to_view(view)? });
+            }
+        }
+
+        in_year.sort_by_key(|holiday| holiday.date.era_days);
+
+        return Ok(in_year);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Computus, Date, Error, Holiday, HolidayDate, HolidayRule, HolidayRules, Observance, Week, Zone
+    };
+
+    use crate::types::{
+        data::{
+            business_calendar::{BusinessCalendar}
+        },
+        planets::{
+            earth::{
+                calendar::{
+                    cutover::{Cutover}
+                }
+            }
+        }
+    };
+
+    fn days_of(holidays: Result<Vec<Holiday>, Error>) -> Vec<(&'static str, u8, u8)> {
+        return holidays.unwrap().iter().map(|holiday| (holiday.name, holiday.date.month().index(), holiday.date.day())).collect();
+    }
+
+    fn day_after_thanksgiving(view: CalendarView, year: u64, time_zone: Zone) -> Result<Date, Error> {
+        return Date::nth_weekday(view, year, 11, Week::Thursday, 4, time_zone)?.checked_add_days(1);
+    }
+
+    #[test]
+    fn test_holidays_in_year() {
+        let rules: HolidayRules = HolidayRules::new([
+            HolidayRule::new("New Year", HolidayDate::Fixed(1, 1)).observed(Observance::NearestWeekday),
+            HolidayRule::new("Orthodox Christmas", HolidayDate::Fixed(12, 25)).in_view(CalendarView::Julian),
+            HolidayRule::new("Orthodox Easter", HolidayDate::Easter(Computus::Orthodox, 0)),
+            HolidayRule::new("Victoria Day", HolidayDate::WeekdayBefore(5, 25, Week::Monday)),
+            HolidayRule::new("Monday after 24 May", HolidayDate::WeekdayAfter(5, 24, Week::Monday)),
+            HolidayRule::new("Juneteenth", HolidayDate::Fixed(6, 19)).observed(Observance::NearestWeekday).since(2021),
+            HolidayRule::new("Independence Day", HolidayDate::Fixed(7, 4)).observed(Observance::NextMonday),
+            HolidayRule::new("Black Friday", HolidayDate::Custom(day_after_thanksgiving)),
+            HolidayRule::new("Boxing Day", HolidayDate::Fixed(12, 26)).observed(Observance::Custom(|date| date.on_or_after(Week::Tuesday)))
+        ]);
+
+        assert_eq!(days_of(rules.holidays_in_year(CalendarView::Gregorian, 2020, Zone::default())), [
+            ("New Year", 1, 1), ("Orthodox Christmas", 1, 7), ("Orthodox Easter", 4, 19), ("Victoria Day", 5, 18), ("Monday after 24 May", 5, 25),
+            ("Independence Day", 7, 6), ("Black Friday", 11, 27), ("Boxing Day", 12, 29)
+        ]);
+
+        // Новый год 2022 года (суббота) отмечается 31 декабря 2021 года.
+        assert_eq!(days_of(rules.holidays_in_year(CalendarView::Gregorian, 2021, Zone::default())), [
+            ("New Year", 1, 1), ("Orthodox Christmas", 1, 7), ("Orthodox Easter", 5, 2), ("Victoria Day", 5, 24), ("Monday after 24 May", 5, 31),
+            ("Juneteenth", 6, 18), ("Independence Day", 7, 5), ("Black Friday", 11, 26), ("Boxing Day", 12, 28), ("New Year", 12, 31)
+        ]);
+
+        let holidays: Vec<Holiday> = rules.holidays_in_year(CalendarView::Julian, 2022, Zone::default()).unwrap();
+
+        assert_eq!((holidays[0].name, holidays[0].date.month().index(), holidays[0].date.day()), ("Orthodox Easter", 4, 11));
+        assert_eq!(holidays.iter().find(|holiday| holiday.name == "Independence Day").map(|holiday| (holiday.actual.month().index(), holiday.actual.day(), holiday.date.day())), Some((6, 21, 21)));
+        assert!(!holidays.iter().any(|holiday| holiday.name == "New Year" && holiday.date.year() != 2022));
+
+        let bad: HolidayRules = HolidayRules::new([HolidayRule::new("Bad", HolidayDate::Fixed(2, 30))]);
+
+        assert_eq!(bad.holidays_in_year(CalendarView::Gregorian, 2024, Zone::default()), Err(Error::InvalidDay(30)));
+    }
+
+    #[test]
+    fn test_rules_missing_in_some_years() {
+        let leap_day: HolidayRules = HolidayRules::new([HolidayRule::new("Leap Day", HolidayDate::Fixed(2, 29))]);
+
+        assert_eq!(days_of(leap_day.holidays_in_year(CalendarView::Gregorian, 2024, Zone::default())), [("Leap Day", 2, 29)]);
+        assert_eq!(days_of(leap_day.holidays_in_year(CalendarView::Gregorian, 2023, Zone::default())), []);
+
+        // Пятый понедельник сентября есть в 2024 и 2025 годах, но не в 2023 и 2026.
+        let fifth_monday: HolidayRules = HolidayRules::new([HolidayRule::new("Fifth Monday", HolidayDate::NthWeekday(9, Week::Monday, 5))]);
+
+        assert_eq!(days_of(fifth_monday.holidays_in_year(CalendarView::Gregorian, 2024, Zone::default())), [("Fifth Monday", 9, 30)]);
+        assert_eq!(days_of(fifth_monday.holidays_in_year(CalendarView::Gregorian, 2025, Zone::default())), [("Fifth Monday", 9, 29)]);
+        assert_eq!(days_of(fifth_monday.holidays_in_year(CalendarView::Gregorian, 2026, Zone::default())), []);
+
+        // 10 октября 1582 года пропущено при переходе в Риме.
+        let rome: HolidayRules = HolidayRules::new([HolidayRule::new("Skipped", HolidayDate::Fixed(10, 10)).in_view(CalendarView::Historical(Cutover::ROME))]);
+
+        assert_eq!(days_of(rome.holidays_in_year(CalendarView::Gregorian, 1582, Zone::default())), []);
+        assert_eq!(days_of(rome.holidays_in_year(CalendarView::Gregorian, 1583, Zone::default())), [("Skipped", 10, 10)]);
+
+        // 1 января 1 года по юлианскому календарю раньше начала эры, 1 января 2 года - 30 декабря 1 года по григорианскому.
+        let julian: HolidayRules = HolidayRules::new([HolidayRule::new("Julian New Year", HolidayDate::Fixed(1, 1)).in_view(CalendarView::Julian)]);
+
+        assert_eq!(days_of(julian.holidays_in_year(CalendarView::Gregorian, 1, Zone::default())), [("Julian New Year", 12, 30)]);
+
+        // Правила, которых нет ни в одном году.
+        for (date, error) in [
+            (HolidayDate::Fixed(13, 1), Error::InvalidMonth(13)),
+            (HolidayDate::Fixed(4, 31), Error::InvalidDay(31)),
+            (HolidayDate::WeekdayAfter(2, 30, Week::Monday), Error::InvalidDay(30)),
+            (HolidayDate::NthWeekday(2, Week::Monday, 6), Error::InvalidOccurrence(6)),
+            (HolidayDate::NthWeekday(9, Week::Monday, 0), Error::InvalidOccurrence(0)),
+            (HolidayDate::NthWeekday(9, Week::Monday, -6), Error::InvalidOccurrence(-6))
+        ] {
+            assert_eq!(HolidayRules::new([HolidayRule::new("Never", date)]).holidays_in_year(CalendarView::Gregorian, 2024, Zone::default()), Err(error), "{date:?}");
+        }
+    }
+
+    #[test]
+    fn test_substitute() {
+        let rules: HolidayRules = HolidayRules::new([
+            HolidayRule::new("Christmas Day", HolidayDate::Fixed(12, 25)).observed(Observance::Substitute),
+            HolidayRule::new("Boxing Day", HolidayDate::Fixed(12, 26)).observed(Observance::Substitute),
+            HolidayRule::new("New Year's Day", HolidayDate::Fixed(1, 1)).observed(Observance::Substitute)
+        ]);
+
+        // 2021: Рождество в субботу, День подарков в воскресенье. 2022: Рождество в воскресенье, День подарков в понедельник.
+        for (year, holidays) in [
+            (2021, [("New Year's Day", 1, 1), ("Christmas Day", 12, 27), ("Boxing Day", 12, 28)]),
+            (2022, [("New Year's Day", 1, 3), ("Boxing Day", 12, 26), ("Christmas Day", 12, 27)]),
+            (2024, [("New Year's Day", 1, 1), ("Christmas Day", 12, 25), ("Boxing Day", 12, 26)])
+        ] {
+            assert_eq!(days_of(rules.holidays_in_year(CalendarView::Gregorian, year, Zone::default())), holidays, "{year}");
+        }
+
+        let calendar: BusinessCalendar = BusinessCalendar::default().with_holidays(
+            rules.holidays_in_year(CalendarView::Gregorian, 2021, Zone::default()).unwrap().into_iter().map(|holiday| holiday.date)
+        );
+
+        let christmas_eve: Date = Date::new(CalendarView::Gregorian, 2021, 12, 24, Zone::default()).unwrap();
+
+        assert_eq!(calendar.next_business_day(&christmas_eve).map(|date| date.day()), Ok(29));
+    }
+}
@@ -0,0 +1,127 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use super::{
+    holiday::{HolidayDate, HolidayRule, HolidayRules, Observance}
+};
+
+use crate::types::{
+    planets::{
+        earth::{
+            calendar::{
+                easter::{Computus},
+                constants::{
+                    week::{Week}
+                }
+            }
+        },
+    },
+};
+
+// Федеральные праздники США (5 U.S.C. 6103), действующие правила.
+const US_FEDERAL: [HolidayRule; 11] = [
+    HolidayRule::new("New Year's Day", HolidayDate::Fixed(1, 1)).observed(Observance::NearestWeekday),
+    HolidayRule::new("Birthday of Martin Luther King, Jr.", HolidayDate::NthWeekday(1, Week::Monday, 3)).since(1986),
+    HolidayRule::new("Washington's Birthday", HolidayDate::NthWeekday(2, Week::Monday, 3)),
+    HolidayRule::new("Memorial Day", HolidayDate::NthWeekday(5, Week::Monday, -1)),
+    HolidayRule::new("Juneteenth National Independence Day", HolidayDate::Fixed(6, 19)).observed(Observance::NearestWeekday).since(2021),
+    HolidayRule::new("Independence Day", HolidayDate::Fixed(7, 4)).observed(Observance::NearestWeekday),
+    HolidayRule::new("Labor Day", HolidayDate::NthWeekday(9, Week::Monday, 1)),
+    HolidayRule::new("Columbus Day", HolidayDate::NthWeekday(10, Week::Monday, 2)),
+    HolidayRule::new("Veterans Day", HolidayDate::Fixed(11, 11)).observed(Observance::NearestWeekday),
+    HolidayRule::new("Thanksgiving Day", HolidayDate::NthWeekday(11, Week::Thursday, 4)),
+    HolidayRule::new("Christmas Day", HolidayDate::Fixed(12, 25)).observed(Observance::NearestWeekday)
+];
+
+// Банковские выходные Англии и Уэльса с года их введения, без разовых переносов (юбилеи, коронации).
+const ENGLAND_AND_WALES: [HolidayRule; 8] = [
+    HolidayRule::new("New Year's Day", HolidayDate::Fixed(1, 1)).observed(Observance::Substitute).since(1974),
+    HolidayRule::new("Good Friday", HolidayDate::Easter(Computus::Western, -2)),
+    HolidayRule::new("Easter Monday", HolidayDate::Easter(Computus::Western, 1)),
+    HolidayRule::new("Early May bank holiday", HolidayDate::NthWeekday(5, Week::Monday, 1)).since(1978),
+    HolidayRule::new("Spring bank holiday", HolidayDate::NthWeekday(5, Week::Monday, -1)).since(1971),
+    HolidayRule::new("Summer bank holiday", HolidayDate::NthWeekday(8, Week::Monday, -1)).since(1971),
+    HolidayRule::new("Christmas Day", HolidayDate::Fixed(12, 25)).observed(Observance::Substitute),
+    HolidayRule::new("Boxing Day", HolidayDate::Fixed(12, 26)).observed(Observance::Substitute)
+];
+
+impl HolidayRules {
+    pub fn us_federal() -> HolidayRules {
+        return HolidayRules::new(US_FEDERAL);
+    }
+
+    pub fn england_and_wales() -> HolidayRules {
+        return HolidayRules::new(ENGLAND_AND_WALES);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        HolidayRules
+    };
+
+    use crate::types::{
+        data::{
+            zone::{Zone}
+        },
+        planets::{
+            earth::{
+                calendar::{
+                    view::{CalendarView}
+                }
+            }
+        }
+    };
+
+    fn days_of(rules: &HolidayRules, year: u64) -> Vec<(u8, u8)> {
+        return rules.holidays_in_year(CalendarView::Gregorian, year, Zone::default()).unwrap().iter().map(|holiday| (holiday.date.month().index(), holiday.date.day())).collect();
+    }
+
+    #[test]
+    fn test_us_federal() {
+        let rules: HolidayRules = HolidayRules::us_federal();
+
+        assert_eq!(days_of(&rules, 2024), [(1, 1), (1, 15), (2, 19), (5, 27), (6, 19), (7, 4), (9, 2), (10, 14), (11, 11), (11, 28), (12, 25)]);
+        // Новый год 2022 года отмечается 31 декабря 2021 года.
+        assert_eq!(days_of(&rules, 2021), [(1, 1), (1, 18), (2, 15), (5, 31), (6, 18), (7, 5), (9, 6), (10, 11), (11, 11), (11, 25), (12, 24), (12, 31)]);
+        assert_eq!(days_of(&rules, 2022).first(), Some(&(1, 17)));
+        assert_eq!(days_of(&rules, 1985).len(), 9);
+    }
+
+    #[test]
+    fn test_england_and_wales() {
+        let rules: HolidayRules = HolidayRules::england_and_wales();
+
+        assert_eq!(days_of(&rules, 2021), [(1, 1), (4, 2), (4, 5), (5, 3), (5, 31), (8, 30), (12, 27), (12, 28)]);
+        assert_eq!(days_of(&rules, 2024), [(1, 1), (3, 29), (4, 1), (5, 6), (5, 27), (8, 26), (12, 25), (12, 26)]);
+        assert_eq!(days_of(&rules, 2027), [(1, 1), (3, 26), (3, 29), (5, 3), (5, 31), (8, 30), (12, 27), (12, 28)]);
+        assert_eq!(days_of(&rules, 1973).first(), Some(&(4, 20)));
+        assert_eq!(days_of(&rules, 1974).first(), Some(&(1, 1)));
+    }
+}
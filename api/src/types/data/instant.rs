@@ -0,0 +1,81 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use std::{
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH
+    }
+};
+
+use crate::types::{
+    error::{Error},
+    planets::{
+        earth::{
+            calendar::{
+                constants::{
+                    seconds::{NANOSECONDS_IN_SECOND}
+                },
+            }
+        }
+    }
+};
+
+// Абсолютный момент времени: секунды и наносекунды UTC от начала unix эпохи, без часового пояса и календаря.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant {
+    pub(crate) seconds: i128,
+    pub(crate) nanoseconds: u32
+}
+
+impl Instant {
+    pub(crate) fn system() -> Instant {
+        match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(duration) => return Instant { seconds: duration.as_secs() as i128, nanoseconds: duration.subsec_nanos() },
+            Err(error) => {
+                // Системные часы установлены раньше 1970 года, секунды округляются вниз, наносекунды всегда вперёд.
+                let duration: Duration = error.duration();
+
+                if duration.subsec_nanos() > 0_u32 {
+                    return Instant { seconds: -(duration.as_secs() as i128) - 1_i128, nanoseconds: NANOSECONDS_IN_SECOND - duration.subsec_nanos() };
+                } else {
+                    return Instant { seconds: -(duration.as_secs() as i128), nanoseconds: 0_u32 };
+                }
+            }
+        }
+    }
+
+    pub(crate) const fn try_of(seconds: i128, nanoseconds: u32) -> Result<Instant, Error> {
+        if nanoseconds >= NANOSECONDS_IN_SECOND {
+            return Err(Error::InvalidNanoseconds(nanoseconds));
+        }
+
+        return Ok(Instant { seconds: seconds, nanoseconds: nanoseconds });
+    }
+}
@@ -0,0 +1,60 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+use super::{
+    date_time::{DateTime},
+    duration::{Duration},
+    month_end::{MonthEnd}
+};
+
+use crate::types::{
+    error::{Error}
+};
+
+// Интервал ISO 8601 в том виде, в котором он записан: начало/конец, начало/длительность или длительность/конец.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interval {
+    Between(DateTime, DateTime),
+    From(DateTime, Duration),
+    Until(Duration, DateTime)
+}
+
+impl Interval {
+    pub fn start(&self, policy: MonthEnd) -> Result<DateTime, Error> {
+        return match self {
+            Interval::Between(start, _) | Interval::From(start, _) => Ok(*start),
+            Interval::Until(duration, end) => end.checked_sub_duration(*duration, policy)
+        };
+    }
+
+    pub fn end(&self, policy: MonthEnd) -> Result<DateTime, Error> {
+        return match self {
+            Interval::Between(_, end) | Interval::Until(_, end) => Ok(*end),
+            Interval::From(start, duration) => start.checked_add_duration(*duration, policy)
+        };
+    }
+}
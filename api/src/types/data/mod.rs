@@ -27,6 +27,18 @@
  */
 
 pub mod date;
+pub mod date_time;
+pub mod instant;
 pub mod time;
 pub mod zone;
+pub mod month_end;
+pub mod period;
+pub mod duration;
+pub mod interval;
+pub mod adjustment;
+pub mod business_calendar;
+pub mod holiday;
+#[cfg(feature = "holiday_rule_sets")]
+pub mod holiday_sets;
+pub mod range;
 pub mod uptime;
\ No newline at end of file
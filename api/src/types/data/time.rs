@@ -26,21 +26,13 @@
  * THE SOFTWARE.
  */
 
-use std::{
-    time::{
-        Duration,
-        SystemTime,
-        UNIX_EPOCH
-    }
-};
-
 use super::{
-    zone::{
-        Sign, Zone
-    }
+    zone::{Zone},
+    instant::{Instant}
 };
 
 use crate::types::{
+    error::{Error},
     planets::{
         earth::{
             calendar::{
@@ -61,44 +53,35 @@ pub struct Time {
     pub hours: u8,
     pub minutes: u8,
     pub seconds: u8,
+    pub nanoseconds: u32,
     pub time_zone: Zone,
-    pub unix_time: u128
+    // Секунды UTC от начала unix эпохи.
+    pub unix_time: i128
 }
 
 impl Time {
-    pub(crate) fn unix() -> Duration {
-        return SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Error calling SystemTime::now().duration_since(UNIX_EPOCH)");
+    pub(crate) fn of(instant: Instant, time_zone: Zone) -> Time {
+        match Time::try_of(instant, time_zone) {
+            Ok(time) => return time,
+            Err(error) => panic!("{}", error)
+        }
     }
 
-    pub(crate) fn of(mut unix_time: u128, time_zone: Zone, zone_in_unix: bool) -> Time {
-        if !zone_in_unix {
-            let time_zone_seconds: u128 = time_zone.to_seconds() as u128;
-
-            if unix_time < time_zone_seconds && time_zone.sign == Sign::Signed {
-                panic!("[OVERFLOW]: Overflow type, unix time - time zone < zero!")
-            } else if unix_time > u128::MAX - time_zone_seconds && time_zone.sign == Sign::Unsigned {
-                panic!("[OVERFLOW]: Overflow type, unix time + time zone > type!")
-            }
-
-            if time_zone.sign == Sign::Signed {
-                unix_time -= time_zone_seconds;
-            } else if time_zone.sign == Sign::Unsigned {
-                unix_time += time_zone_seconds;
-            }
-        }
+    pub(crate) fn try_of(instant: Instant, time_zone: Zone) -> Result<Time, Error> {
+        // Signed: unix time - time zone < type, Unsigned: unix time + time zone > type.
+        let local_seconds: i128 = instant.seconds.checked_add(time_zone.offset()).ok_or(Error::Overflow)?;
 
-        return Time::from_seconds(unix_time, time_zone);
+        return Ok(Time::from_seconds(local_seconds, instant.nanoseconds, time_zone));
     }
 
-    pub(crate) const fn from_seconds(unix: u128, zone: Zone) -> Time {
+    pub(crate) const fn from_seconds(local_seconds: i128, nanoseconds: u32, zone: Zone) -> Time {
         return Time {
-            hours: ((unix % SECONDS_IN_DAY) / SECONDS_IN_HOUR) as u8,
-            minutes: ((unix % SECONDS_IN_HOUR) / SECONDS_IN_MINUTE) as u8,
-            seconds: (unix % SECONDS_IN_MINUTE) as u8,
+            hours: (local_seconds.rem_euclid(SECONDS_IN_DAY as i128) / SECONDS_IN_HOUR as i128) as u8,
+            minutes: (local_seconds.rem_euclid(SECONDS_IN_HOUR as i128) / SECONDS_IN_MINUTE as i128) as u8,
+            seconds: local_seconds.rem_euclid(SECONDS_IN_MINUTE as i128) as u8,
+            nanoseconds: nanoseconds,
             time_zone: zone,
-            unix_time: unix
+            unix_time: local_seconds - zone.offset()
         }
     }
 }
@@ -35,7 +35,8 @@ use crate::types::{
                         SECONDS_IN_DAY,
                         SECONDS_IN_HOUR,
                         SECONDS_IN_MINUTE,
-                        SECONDS_IN_WEEK
+                        SECONDS_IN_WEEK,
+                        NANOSECONDS_IN_SECOND
                     }
                 },
             }
@@ -49,7 +50,8 @@ pub struct Uptime {
     pub days: u8,
     pub hours: u8,
     pub minutes: u8,
-    pub seconds: u8
+    pub seconds: u8,
+    pub nanoseconds: u32
 }
 
 impl Uptime {
@@ -61,13 +63,18 @@ impl Uptime {
                self.seconds as u128;
     }
 
-    pub(crate) const fn from_seconds(seconds: u128) -> Uptime {
+    pub const fn to_nanoseconds(&self) -> u128 {
+        return self.to_seconds() * NANOSECONDS_IN_SECOND as u128 + self.nanoseconds as u128;
+    }
+
+    pub(crate) const fn from_seconds(seconds: u128, nanoseconds: u32) -> Uptime {
         return Uptime {
             weeks: seconds / SECONDS_IN_WEEK,
             days: ((seconds % SECONDS_IN_WEEK) / SECONDS_IN_DAY) as u8,
             hours: ((seconds % SECONDS_IN_DAY) / SECONDS_IN_HOUR) as u8,
             minutes: ((seconds % SECONDS_IN_HOUR) / SECONDS_IN_MINUTE) as u8,
-            seconds: (seconds % SECONDS_IN_MINUTE) as u8
+            seconds: (seconds % SECONDS_IN_MINUTE) as u8,
+            nanoseconds: nanoseconds
         }
     }
 }
\ No newline at end of file
@@ -27,11 +27,13 @@
  */
 
 use crate::types::{
+    error::{Error},
     planets::{
         earth::{
             calendar::{
                 constants::{
-                    seconds::{SECONDS_IN_MINUTE, SECONDS_IN_HOUR}
+                    seconds::{SECONDS_IN_MINUTE, SECONDS_IN_HOUR},
+                    hours::{HOURS_IN_A_DAY}
                 },
             }
         }
@@ -54,12 +56,28 @@ pub struct Zone {
 }
 
 impl Zone {
+    pub const fn try_new(sign: Sign, hours: u8, minutes: u8, seconds: u8) -> Result<Zone, Error> {
+        if hours >= HOURS_IN_A_DAY || minutes as u128 >= SECONDS_IN_MINUTE || seconds as u128 >= SECONDS_IN_MINUTE {
+            return Err(Error::InvalidZone);
+        }
+
+        return Ok(Zone { sign: sign, hours: hours, minutes: minutes, seconds: seconds });
+    }
+
     pub const fn to_seconds(&self) -> u32 {
         return ((self.hours as u32) * SECONDS_IN_HOUR as u32) +
                ((self.minutes as u32) * SECONDS_IN_MINUTE as u32) +
                (self.seconds as u32);
     }
 
+    // Смещение местного времени относительно UTC: местные секунды = UTC + смещение.
+    pub const fn offset(&self) -> i128 {
+        return match self.sign {
+            Sign::Signed => -(self.to_seconds() as i128),
+            Sign::Unsigned => self.to_seconds() as i128,
+        };
+    }
+
     pub const fn from_seconds(sign: Sign, seconds: u32) -> Zone {
         return Zone {
             sign: sign,
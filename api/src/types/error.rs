@@ -0,0 +1,92 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use crate::types::{
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+            }
+        }
+    }
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    Overflow,
+    InvalidYear(u64),
+    InvalidMonth(u8),
+    InvalidDay(u8),
+    InvalidWeekDay(u8),
+    InvalidHour(u8),
+    InvalidMinute(u8),
+    InvalidSecond(u8),
+    InvalidZone,
+    InvalidNanoseconds(u32),
+    InvalidStep,
+    InvalidWeek(u8),
+    InvalidYearDay(u16),
+    InvalidFormat(usize),
+    WriteFailed,
+    MissingField(char),
+    ConflictingField(usize),
+    SkippedDay(u8),
+    InvalidOccurrence(i8),
+    InvalidWeekend,
+    OutsideEra(CalendarView)
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Overflow => write!(f, "[OVERFLOW]: Value is out of range of the type!"),
+            Error::InvalidYear(year) => write!(f, "[INVALID]: Invalid year: {}", year),
+            Error::InvalidMonth(month) => write!(f, "[INVALID]: Invalid month: {}", month),
+            Error::InvalidDay(day) => write!(f, "[INVALID]: Invalid day: {}", day),
+            Error::InvalidWeekDay(week_day) => write!(f, "[INVALID]: Invalid week day: {}", week_day),
+            Error::InvalidHour(hour) => write!(f, "[INVALID]: Invalid hour: {}", hour),
+            Error::InvalidMinute(minute) => write!(f, "[INVALID]: Invalid minute: {}", minute),
+            Error::InvalidSecond(second) => write!(f, "[INVALID]: Invalid second: {}", second),
+            Error::InvalidZone => write!(f, "[INVALID]: Invalid time zone!"),
+            Error::InvalidNanoseconds(nanoseconds) => write!(f, "[INVALID]: Invalid nanoseconds: {}", nanoseconds),
+            Error::InvalidStep => write!(f, "[INVALID]: Step of range cannot be zero!"),
+            Error::InvalidWeek(week) => write!(f, "[INVALID]: Invalid week: {}", week),
+            Error::InvalidYearDay(year_day) => write!(f, "[INVALID]: Invalid day of year: {}", year_day),
+            Error::InvalidFormat(position) => write!(f, "[INVALID]: Unexpected input at position: {}", position),
+            Error::WriteFailed => write!(f, "[ERROR]: Formatted output could not be written!"),
+            Error::MissingField(specifier) => write!(f, "[INVALID]: Not enough fields to build a date, missing: %{}", specifier),
+            Error::ConflictingField(position) => write!(f, "[INVALID]: Field contradicts other fields at position: {}", position),
+            Error::SkippedDay(day) => write!(f, "[INVALID]: Day was skipped by the calendar reform: {}", day),
+            Error::InvalidOccurrence(nth) => write!(f, "[INVALID]: Month has no such occurrence of week day: {}", nth),
+            Error::InvalidWeekend => write!(f, "[INVALID]: Weekend cannot take the whole week!"),
+            Error::OutsideEra(view) => write!(f, "[IMPOSSIBLE]: This presentation of days is missing in CE (Current Era) of {:?} Calendar!", view)
+        }
+    }
+}
+
+impl std::error::Error for Error {}
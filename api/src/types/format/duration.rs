@@ -0,0 +1,338 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+use crate::types::{
+    error::{Error},
+    format::{
+        scanner::{Scanner},
+        iso8601::{Representation, Notation}
+    },
+    data::{
+        date::{Date},
+        date_time::{DateTime},
+        duration::{Duration},
+        interval::{Interval},
+        zone::{Sign, Zone}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                constants::{
+                    seconds::{SECONDS_IN_HOUR, SECONDS_IN_MINUTE, NANOSECONDS_IN_SECOND}
+                }
+            }
+        }
+    }
+};
+
+// Порядок обозначений длительности: годы, месяцы, недели, дни, затем после "T" часы, минуты, секунды.
+const TIME_DESIGNATORS_START: usize = 4_usize;
+
+impl Duration {
+    // Дробная часть допустима только у последней составляющей времени (PT0.5S, PT1.5H): она переводится в минуты, секунды и наносекунды.
+    pub fn parse_iso8601(text: &str) -> Result<Duration, Error> {
+        let mut scanner: Scanner = Scanner::new(text);
+
+        let sign: Sign = if scanner.eat(b'-') {
+            Sign::Signed
+        } else {
+            scanner.eat(b'+');
+            Sign::Unsigned
+        };
+
+        scanner.expect(b'P')?;
+
+        let mut duration: Duration = Duration { sign: sign, ..Duration::default() };
+
+        let (mut next, mut time, mut components): (usize, bool, usize) = (0_usize, false, 0_usize);
+
+        while !scanner.is_end() {
+            if !time && scanner.eat(b'T') {
+                (time, next) = (true, TIME_DESIGNATORS_START);
+
+                if scanner.is_end() {
+                    return Err(scanner.error());
+                }
+                continue;
+            }
+
+            let value_position: usize = scanner.position();
+
+            let value: u64 = scanner.number_up_to(20_usize)?;
+
+            let fraction: Option<u32> = if scanner.eat(b'.') || scanner.eat(b',') { Some(scanner.fraction()?) } else { None };
+
+            let position: usize = scanner.position();
+
+            let index: usize = match (time, scanner.next_byte()) {
+                (false, Some(b'Y')) => 0_usize,
+                (false, Some(b'M')) => 1_usize,
+                (false, Some(b'W')) => 2_usize,
+                (false, Some(b'D')) => 3_usize,
+                (true, Some(b'H')) => 4_usize,
+                (true, Some(b'M')) => 5_usize,
+                (true, Some(b'S')) => 6_usize,
+                _ => return Err(Error::InvalidFormat(position))
+            };
+
+            if index < next {
+                return Err(Error::InvalidFormat(position));
+            }
+
+            match index {
+                0_usize => duration.years = value,
+                1_usize => duration.months = value,
+                2_usize => duration.weeks = value,
+                3_usize => duration.days = value,
+                4_usize => duration.hours = value,
+                5_usize => duration.minutes = value,
+                _ => duration.seconds = value
+            }
+
+            if let Some(fraction) = fraction {
+                if index < TIME_DESIGNATORS_START {
+                    return Err(Error::InvalidFormat(value_position));
+                }
+
+                scanner.finish()?;
+
+                let unit: u128 = match index {
+                    4_usize => SECONDS_IN_HOUR,
+                    5_usize => SECONDS_IN_MINUTE,
+                    _ => 1_u128
+                };
+
+                let nanoseconds: u128 = fraction as u128 * unit;
+                let seconds: u128 = nanoseconds / NANOSECONDS_IN_SECOND as u128;
+
+                duration.minutes += (seconds / SECONDS_IN_MINUTE) as u64;
+                duration.seconds += (seconds % SECONDS_IN_MINUTE) as u64;
+                duration.nanoseconds = (nanoseconds % NANOSECONDS_IN_SECOND as u128) as u32;
+            }
+
+            (next, components) = (index + 1_usize, components + 1_usize);
+        }
+
+        if components == 0_usize {
+            return Err(scanner.error());
+        }
+
+        return Ok(duration);
+    }
+
+    // Нулевые составляющие опускаются, нулевая длительность - "PT0S".
+    pub fn to_iso8601(&self) -> String {
+        let (mut date, mut time): (String, String) = (String::new(), String::new());
+
+        for (value, designator) in [(self.years, 'Y'), (self.months, 'M'), (self.weeks, 'W'), (self.days, 'D')] {
+            if value > 0_u64 {
+                date.push_str(&format!("{value}{designator}"));
+            }
+        }
+
+        for (value, designator) in [(self.hours, 'H'), (self.minutes, 'M')] {
+            if value > 0_u64 {
+                time.push_str(&format!("{value}{designator}"));
+            }
+        }
+
+        if self.nanoseconds > 0_u32 {
+            time.push_str(&format!("{}.{}S", self.seconds, format!("{:09}", self.nanoseconds).trim_end_matches('0')));
+        } else if self.seconds > 0_u64 {
+            time.push_str(&format!("{}S", self.seconds));
+        }
+
+        if date.is_empty() && time.is_empty() {
+            return String::from("PT0S");
+        } else if !time.is_empty() {
+            time.insert(0_usize, 'T');
+        }
+
+        return format!("{}P{date}{time}", match self.sign { Sign::Signed => "-", Sign::Unsigned => "" });
+    }
+}
+
+fn is_duration(text: &str) -> bool {
+    return text.starts_with('P') || text.starts_with("-P") || text.starts_with("+P");
+}
+
+// Ошибка формата части интервала указывает позицию во всём тексте.
+fn shift_position(error: Error, offset: usize) -> Error {
+    return match error {
+        Error::InvalidFormat(position) => Error::InvalidFormat(position + offset),
+        error => error
+    };
+}
+
+// Точка интервала: дата ("2024-03-01", полночь time_zone) или дата и время ("2024-03-01T10:00Z").
+fn parse_point(text: &str, offset: usize, view: CalendarView, time_zone: Zone) -> Result<DateTime, Error> {
+    let date_time: DateTime = if text.contains('T') {
+        DateTime::parse_iso8601(text, time_zone)
+    } else {
+        Date::parse_iso8601(text, time_zone).map(DateTime::of)
+    }.map_err(|error| shift_position(error, offset))?;
+
+    return date_time.to_view(view);
+}
+
+// Полночь без долей секунды записывается одной датой.
+fn format_point(point: &DateTime, representation: Representation, notation: Notation) -> Result<String, Error> {
+    if point.hours() == 0_u8 && point.minutes() == 0_u8 && point.seconds() == 0_u8 && point.nanoseconds() == 0_u32 {
+        return point.date.to_iso8601(representation, notation);
+    }
+
+    return point.to_iso8601(representation, notation);
+}
+
+impl Interval {
+    // Точки интервала записаны по григорианскому календарю и переводятся в view, в нём же применяется длительность.
+    pub fn parse_iso8601(text: &str, view: CalendarView, time_zone: Zone) -> Result<Interval, Error> {
+        let separator: usize = text.find('/').ok_or(Error::InvalidFormat(text.len()))?;
+
+        let (first, second): (&str, &str) = (&text[..separator], &text[separator + 1_usize..]);
+
+        let offset: usize = separator + 1_usize;
+
+        return match (is_duration(first), is_duration(second)) {
+            (false, false) => Ok(Interval::Between(parse_point(first, 0_usize, view, time_zone)?, parse_point(second, offset, view, time_zone)?)),
+            (false, true) => Ok(Interval::From(
+                parse_point(first, 0_usize, view, time_zone)?,
+                Duration::parse_iso8601(second).map_err(|error| shift_position(error, offset))?
+            )),
+            (true, false) => Ok(Interval::Until(Duration::parse_iso8601(first)?, parse_point(second, offset, view, time_zone)?)),
+            (true, true) => Err(Error::InvalidFormat(offset))
+        };
+    }
+
+    pub fn to_iso8601(&self, representation: Representation, notation: Notation) -> Result<String, Error> {
+        return Ok(match self {
+            Interval::Between(start, end) => format!("{}/{}", format_point(start, representation, notation)?, format_point(end, representation, notation)?),
+            Interval::From(start, duration) => format!("{}/{}", format_point(start, representation, notation)?, duration.to_iso8601()),
+            Interval::Until(duration, end) => format!("{}/{}", duration.to_iso8601(), format_point(end, representation, notation)?)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Date, DateTime, Duration, Error, Interval, Notation, Representation, Sign, Zone
+    };
+
+    use crate::types::{
+        data::{
+            month_end::{MonthEnd}
+        }
+    };
+
+    #[test]
+    fn test_duration_iso8601() {
+        for (text, duration, canonical) in [
+            ("P1Y2M10DT2H30M", Duration { years: 1, months: 2, days: 10, hours: 2, minutes: 30, ..Duration::default() }, "P1Y2M10DT2H30M"),
+            ("PT0.5S", Duration { nanoseconds: 500_000_000, ..Duration::default() }, "PT0.5S"),
+            ("P3W", Duration { weeks: 3, ..Duration::default() }, "P3W"),
+            ("PT1,25H", Duration { hours: 1, minutes: 15, ..Duration::default() }, "PT1H15M"),
+            ("PT2.000000001M", Duration { minutes: 2, nanoseconds: 60, ..Duration::default() }, "PT2M0.00000006S"),
+            ("-P1DT0S", Duration { sign: Sign::Signed, days: 1, ..Duration::default() }, "-P1D"),
+            ("+P0D", Duration::default(), "PT0S")
+        ] {
+            assert_eq!(Duration::parse_iso8601(text), Ok(duration), "{text}");
+            assert_eq!(duration.to_iso8601(), canonical);
+            assert_eq!(Duration::parse_iso8601(canonical), Ok(duration), "{canonical}");
+        }
+
+        for (text, position) in [("P", 1), ("PT", 2), ("1D", 0), ("P1H", 2), ("PT1D", 3), ("P1M1Y", 4), ("P1D1D", 4), ("P1.5Y", 1), ("PT1.5M30S", 6), ("P1DT", 4)] {
+            assert_eq!(Duration::parse_iso8601(text), Err(Error::InvalidFormat(position)), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_duration_arithmetic() {
+        let time_zone: Zone = Zone { sign: Sign::Signed, hours: 3_u8, minutes: 0_u8, seconds: 0_u8 };
+        let month: Duration = Duration { months: 1, ..Duration::default() };
+
+        let date: Date = Date::new(CalendarView::Gregorian, 2024, 1, 31, time_zone).unwrap();
+
+        assert!(date.checked_add_duration(month, MonthEnd::Clamp).unwrap().same_civil_fields(&Date::new(CalendarView::Gregorian, 2024, 2, 29, time_zone).unwrap()));
+        assert!(date.checked_add_duration(month, MonthEnd::RollOver).unwrap().same_civil_fields(&Date::new(CalendarView::Gregorian, 2024, 3, 2, time_zone).unwrap()));
+        assert_eq!(date.checked_add_duration(month, MonthEnd::Reject), Err(Error::InvalidDay(31)));
+
+        // Месяцы отсчитываются по календарю даты: в юлианском 1900 год високосный.
+        let julian: Date = Date::new(CalendarView::Julian, 1900, 1, 31, time_zone).unwrap();
+
+        assert_eq!(julian.checked_add_duration(month, MonthEnd::Clamp).map(|date| (date.month().index(), date.day())), Ok((2, 29)));
+
+        let date_time: DateTime = DateTime::new(CalendarView::Solar, 2024, 3, 1, 22, 30, 0, 0, time_zone).unwrap();
+        let duration: Duration = Duration::parse_iso8601("P1Y1W1DT1H30M0.5S").unwrap();
+
+        let later: DateTime = date_time.checked_add_duration(duration, MonthEnd::Clamp).unwrap();
+
+        assert_eq!((later.year(), later.month().index(), later.day(), later.hours(), later.minutes(), later.seconds(), later.nanoseconds()), (2025, 3, 10, 0, 0, 0, 500_000_000));
+        assert_eq!(later.checked_sub_duration(duration, MonthEnd::Clamp), Ok(date_time));
+        assert_eq!(date_time.checked_add_duration(Duration { sign: Sign::Signed, ..duration }, MonthEnd::Clamp), date_time.checked_sub_duration(duration, MonthEnd::Clamp));
+        assert_eq!(date_time.checked_sub_duration(Duration { years: 2024, ..Duration::default() }, MonthEnd::Clamp), Err(Error::OutsideEra(CalendarView::Solar)));
+    }
+
+    #[test]
+    fn test_interval_iso8601() {
+        let utc: Zone = Zone::default();
+
+        let point = |view: CalendarView, year: u64, month: u8, day: u8| -> DateTime {
+            return DateTime::new(view, year, month, day, 0, 0, 0, 0, utc).unwrap();
+        };
+
+        for text in ["2024-03-01/P1M", "P1M/2024-04-01", "2024-03-01/2024-04-01"] {
+            let interval: Interval = Interval::parse_iso8601(text, CalendarView::Gregorian, utc).unwrap();
+
+            assert_eq!(interval.start(MonthEnd::Clamp), Ok(point(CalendarView::Gregorian, 2024, 3, 1)), "{text}");
+            assert_eq!(interval.end(MonthEnd::Clamp), Ok(point(CalendarView::Gregorian, 2024, 4, 1)), "{text}");
+            assert_eq!(interval.to_iso8601(Representation::Calendar, Notation::Extended).unwrap(), text);
+        }
+
+        // Длительность применяется в календаре view: юлианский месяц от 1 марта (14 марта по григорианскому).
+        let julian: Interval = Interval::parse_iso8601("2024-03-14/P1M", CalendarView::Julian, utc).unwrap();
+
+        assert!(julian.end(MonthEnd::Clamp).unwrap().date().same_civil_fields(&point(CalendarView::Julian, 2024, 4, 1).date()));
+        assert_eq!(julian.to_iso8601(Representation::Calendar, Notation::Basic).unwrap(), "20240314/P1M");
+
+        let hours: Interval = Interval::parse_iso8601("2024-03-01T22:00:00+05:30/PT2H30M", CalendarView::Gregorian, utc).unwrap();
+
+        assert_eq!(hours.end(MonthEnd::Clamp).unwrap().to_iso8601(Representation::Calendar, Notation::Extended).unwrap(), "2024-03-02T00:30:00+05:30");
+        assert_eq!(hours.to_iso8601(Representation::Week, Notation::Extended).unwrap(), "2024-W09-5T22:00:00+05:30/PT2H30M");
+
+        for (text, error) in [
+            ("2024-03-01", Error::InvalidFormat(10)),
+            ("P1D/P2D", Error::InvalidFormat(4)),
+            ("2024-03-01/P1X", Error::InvalidFormat(13)),
+            ("2024-03-01/2024-04-0x", Error::InvalidFormat(20)),
+            ("2024-03-01/2024-13-01", Error::InvalidMonth(13))
+        ] {
+            assert_eq!(Interval::parse_iso8601(text, CalendarView::Gregorian, utc), Err(error), "{text}");
+        }
+    }
+}
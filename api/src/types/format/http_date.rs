@@ -0,0 +1,208 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+use crate::types::{
+    error::{Error},
+    format::{
+        scanner::{Scanner},
+        rfc2822::{parse_month, parse_week_day, check_week_day}
+    },
+    data::{
+        date::{Date},
+        date_time::{DateTime},
+        zone::{Zone}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                traits::{
+                    day_of_week::{RataDie}
+                }
+            }
+        }
+    }
+};
+
+// Две цифры года RFC 850: год, оказавшийся больше чем на 50 лет в будущем, относится к прошлому веку (RFC 9110, раздел 5.6.7).
+fn full_year(short_year: u64, current_year: u64) -> u64 {
+    let year: u64 = current_year - current_year % 100_u64 + short_year;
+
+    if year > current_year + 50_u64 {
+        return year - 100_u64;
+    }
+
+    return year;
+}
+
+fn parse_time(scanner: &mut Scanner) -> Result<(u8, u8, u8), Error> {
+    let hours: u8 = scanner.number(2_usize)? as u8;
+    scanner.expect(b':')?;
+    let minutes: u8 = scanner.number(2_usize)? as u8;
+    scanner.expect(b':')?;
+    let seconds: u8 = scanner.number(2_usize)? as u8;
+
+    return Ok((hours, minutes, seconds));
+}
+
+// Дата HTTP всегда в GMT, поэтому формат описывает момент в UTC.
+fn parse(text: &str, current_year: u64) -> Result<DateTime, Error> {
+    let mut scanner: Scanner = Scanner::new(text);
+
+    let (day, month, year, (hours, minutes, seconds), week_day): (u8, u8, u64, (u8, u8, u8), u8);
+
+    // RFC 850: "Sunday, 06-Nov-94 08:49:37 GMT".
+    if let Ok(full_week_day) = parse_week_day(&mut scanner, true, false) {
+        week_day = full_week_day;
+
+        scanner.expect(b',')?;
+        scanner.expect(b' ')?;
+        day = scanner.number(2_usize)? as u8;
+        scanner.expect(b'-')?;
+        month = parse_month(&mut scanner, false)?;
+        scanner.expect(b'-')?;
+        year = full_year(scanner.number(2_usize)?, current_year);
+        scanner.expect(b' ')?;
+        (hours, minutes, seconds) = parse_time(&mut scanner)?;
+        scanner.expect(b' ')?;
+
+        if !scanner.eat_str("GMT") {
+            return Err(scanner.error());
+        }
+    } else {
+        week_day = parse_week_day(&mut scanner, false, false)?;
+
+        if scanner.eat(b',') {
+            // IMF-fixdate: "Sun, 06 Nov 1994 08:49:37 GMT".
+            scanner.expect(b' ')?;
+            day = scanner.number(2_usize)? as u8;
+            scanner.expect(b' ')?;
+            month = parse_month(&mut scanner, false)?;
+            scanner.expect(b' ')?;
+            year = scanner.number(4_usize)?;
+            scanner.expect(b' ')?;
+            (hours, minutes, seconds) = parse_time(&mut scanner)?;
+            scanner.expect(b' ')?;
+
+            if !scanner.eat_str("GMT") {
+                return Err(scanner.error());
+            }
+        } else {
+            // asctime: "Sun Nov  6 08:49:37 1994".
+            scanner.expect(b' ')?;
+            month = parse_month(&mut scanner, false)?;
+            scanner.expect(b' ')?;
+            day = if scanner.eat(b' ') { scanner.number(1_usize)? } else { scanner.number(2_usize)? } as u8;
+            scanner.expect(b' ')?;
+            (hours, minutes, seconds) = parse_time(&mut scanner)?;
+            scanner.expect(b' ')?;
+            year = scanner.number(4_usize)?;
+        }
+    }
+
+    scanner.finish()?;
+
+    let date_time: DateTime = DateTime::new(CalendarView::Gregorian, year, month, day, hours, minutes, seconds, 0_u32, Zone::default())?;
+
+    check_week_day(&date_time, Some((week_day, 0_usize)))?;
+
+    return Ok(date_time);
+}
+
+impl DateTime {
+    // IMF-fixdate, а также устаревшие RFC 850 и asctime, которые получатель HTTP обязан принимать.
+    pub fn parse_http_date(text: &str) -> Result<DateTime, Error> {
+        return parse(text, Date::try_utc(CalendarView::Gregorian)?.year);
+    }
+
+    // Всегда IMF-fixdate: момент переводится в UTC и григорианский календарь.
+    pub fn to_http_date(&self) -> Result<String, Error> {
+        let date_time: DateTime = self.to_zone(Zone::default())?.to_view(CalendarView::Gregorian)?;
+
+        return Ok(format!(
+            "{week_day}, {dd:02} {month} {yyyy:04} {hours:02}:{minutes:02}:{seconds:02} GMT",
+            week_day = &<Date as RataDie>::week_day(&date_time.date).name()[..3_usize],
+            dd = date_time.day(), month = &date_time.month().name()[..3_usize], yyyy = date_time.year(),
+            hours = date_time.hours(), minutes = date_time.minutes(), seconds = date_time.seconds()
+        ));
+    }
+}
+
+impl Date {
+    pub fn parse_http_date(text: &str) -> Result<Date, Error> {
+        return Ok(DateTime::parse_http_date(text)?.date);
+    }
+
+    pub fn to_http_date(&self) -> Result<String, Error> {
+        return DateTime::of(*self).to_http_date();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, DateTime, Error, Zone,
+        full_year, parse
+    };
+
+    use crate::types::{
+        data::{
+            zone::{Sign}
+        }
+    };
+
+    #[test]
+    fn test_http_date() {
+        let date_time: DateTime = DateTime::new(CalendarView::Gregorian, 1994, 11, 6, 8, 49, 37, 0, Zone::default()).unwrap();
+
+        for text in ["Sun, 06 Nov 1994 08:49:37 GMT", "Sunday, 06-Nov-94 08:49:37 GMT", "Sun Nov  6 08:49:37 1994"] {
+            assert_eq!(DateTime::parse_http_date(text), Ok(date_time), "{text}");
+        }
+
+        assert_eq!(date_time.to_http_date().unwrap(), "Sun, 06 Nov 1994 08:49:37 GMT");
+
+        let moscow: DateTime = date_time.to_zone(Zone { sign: Sign::Unsigned, hours: 3_u8, minutes: 0_u8, seconds: 0_u8 }).unwrap();
+
+        assert_eq!(moscow.to_view(CalendarView::Julian).unwrap().to_http_date().unwrap(), "Sun, 06 Nov 1994 08:49:37 GMT");
+
+        assert_eq!((full_year(94, 2024), full_year(74, 2024), full_year(75, 2024), full_year(24, 2080)), (1994, 2074, 1975, 2024));
+        assert_eq!(parse("Thursday, 01-Mar-74 00:00:00 GMT", 2024).map(|value| value.year()), Ok(2074));
+
+        for (text, error) in [
+            ("Mon, 06 Nov 1994 08:49:37 GMT", Error::ConflictingField(0)),
+            ("sun, 06 Nov 1994 08:49:37 GMT", Error::InvalidFormat(0)),
+            ("Sun, 6 Nov 1994 08:49:37 GMT", Error::InvalidFormat(6)),
+            ("Sun, 06 Nov 1994 08:49:37 UTC", Error::InvalidFormat(26)),
+            ("Sun, 06 Nov 1994 08:49 GMT", Error::InvalidFormat(22)),
+            ("Sun Nov 6 08:49:37 1994", Error::InvalidFormat(9)),
+            ("Sunday, 06 Nov 1994 08:49:37 GMT", Error::InvalidFormat(10)),
+            ("Sun, 31 Nov 1994 08:49:37 GMT", Error::InvalidDay(31))
+        ] {
+            assert_eq!(DateTime::parse_http_date(text), Err(error), "{text}");
+        }
+    }
+}
@@ -0,0 +1,495 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+use crate::types::{
+    error::{Error},
+    format::{
+        scanner::{Scanner}
+    },
+    data::{
+        date::{Date},
+        date_time::{DateTime},
+        time::{Time},
+        zone::{Sign, Zone}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                constants::{
+                    week::{Week, DAYS_IN_WEEK},
+                    hours::{HOURS_IN_A_DAY},
+                    minutes::{MINUTES_IN_HOUR},
+                    seconds::{SECONDS_IN_HOUR, SECONDS_IN_MINUTE}
+                },
+                functions::{
+                    days_from_presentation_date, days_in_month, weeks_in_year
+                }
+            }
+        }
+    }
+};
+
+// Запись даты ISO 8601: календарная (2024-03-01), порядковая (2024-061) или недельная (2024-W09-5).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Representation {
+    #[default]
+    Calendar,
+    Ordinal,
+    Week
+}
+
+// Расширенная запись с разделителями (2024-03-01T12:30:00+05:30) или базовая без них (20240301T123000+0530).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Notation {
+    #[default]
+    Extended,
+    Basic
+}
+
+const YEAR_DIGITS: usize = 4_usize;
+const LAST_BASIC_YEAR: u64 = 9999_u64;
+
+fn gregorian_date(era_days: u128, time_zone: Zone) -> Result<Date, Error> {
+    return Date::new(CalendarView::Gregorian, 1_u64, 1_u8, 1_u8, time_zone)?.with_era_days(era_days);
+}
+
+// Год из четырёх цифр, расширенный (больше 9999) - со знаком "+" и не меньше пяти цифр.
+fn parse_year(scanner: &mut Scanner) -> Result<u64, Error> {
+    let expanded: bool = scanner.eat(b'+');
+
+    let width: usize = if !expanded {
+        YEAR_DIGITS
+    } else {
+        // В базовой записи граница расширенного года не отмечена: при двух возможных прочтениях выбирается календарная дата.
+        let digits: usize = scanner.digits_ahead();
+
+        match scanner.peek_at(digits) {
+            Some(b'-') | Some(b'W') => digits,
+            _ if digits >= YEAR_DIGITS + 1_usize + 4_usize => digits - 4_usize,
+            _ => digits.saturating_sub(3_usize)
+        }
+    };
+
+    if expanded && width <= YEAR_DIGITS {
+        return Err(scanner.error());
+    }
+
+    let year: u64 = scanner.number(width)?;
+
+    if year == 0_u64 {
+        return Err(Error::InvalidYear(year));
+    }
+
+    return Ok(year);
+}
+
+// Дни эры григорианского календаря.
+fn parse_date(scanner: &mut Scanner) -> Result<u128, Error> {
+    let year: u64 = parse_year(scanner)?;
+
+    let extended: bool = scanner.eat(b'-');
+
+    if scanner.eat(b'W') {
+        let week: u8 = scanner.number(2_usize)? as u8;
+
+        if extended {
+            scanner.expect(b'-')?;
+        }
+
+        let day: u8 = scanner.number(1_usize)? as u8;
+
+        if week == 0_u8 || week > weeks_in_year(CalendarView::Gregorian, year)? {
+            return Err(Error::InvalidWeek(week));
+        } else if day == 0_u8 || day > DAYS_IN_WEEK {
+            return Err(Error::InvalidWeekDay(day));
+        }
+
+        return Ok(Date::from_iso_week(CalendarView::Gregorian, year, week, Week::from(day), Zone::default())?.era_days);
+    }
+
+    if scanner.digits_ahead() == 3_usize {
+        let year_day: u16 = scanner.number(3_usize)? as u16;
+
+        return Ok(Date::from_ordinal(CalendarView::Gregorian, year, year_day, Zone::default())?.era_days);
+    }
+
+    let month: u8 = scanner.number(2_usize)? as u8;
+
+    if extended {
+        scanner.expect(b'-')?;
+    }
+
+    let day: u8 = scanner.number(2_usize)? as u8;
+
+    if day == 0_u8 || day > days_in_month(CalendarView::Gregorian, year, month)? {
+        return Err(Error::InvalidDay(day));
+    }
+
+    return Ok(days_from_presentation_date(CalendarView::Gregorian, year, month, day));
+}
+
+// Часы и минуты обязательны, секунды необязательны, дробная часть (через "." или ",") допускается только у секунд.
+fn parse_time(scanner: &mut Scanner) -> Result<(u8, u8, u8, u32), Error> {
+    let hours: u8 = scanner.number(2_usize)? as u8;
+
+    let extended: bool = scanner.eat(b':');
+
+    let minutes: u8 = scanner.number(2_usize)? as u8;
+
+    let (mut seconds, mut nanoseconds): (u8, u32) = (0_u8, 0_u32);
+
+    if (extended && scanner.eat(b':')) || (!extended && scanner.digits_ahead() > 0_usize) {
+        seconds = scanner.number(2_usize)? as u8;
+
+        if scanner.eat(b'.') || scanner.eat(b',') {
+            nanoseconds = scanner.fraction()?;
+        }
+    }
+
+    if hours >= HOURS_IN_A_DAY {
+        return Err(Error::InvalidHour(hours));
+    } else if minutes >= MINUTES_IN_HOUR {
+        return Err(Error::InvalidMinute(minutes));
+    } else if seconds as u128 >= SECONDS_IN_MINUTE {
+        return Err(Error::InvalidSecond(seconds));
+    }
+
+    return Ok((hours, minutes, seconds, nanoseconds));
+}
+
+// "Z", "+hh", "+hh:mm", "+hhmm" (и с секундами: "+hh:mm:ss", "+hhmmss"), None - смещение не указано.
+pub(crate) fn parse_zone(scanner: &mut Scanner) -> Result<Option<Zone>, Error> {
+    if scanner.eat(b'Z') {
+        return Ok(Some(Zone::default()));
+    }
+
+    let sign: Sign = if scanner.eat(b'+') {
+        Sign::Unsigned
+    } else if scanner.eat(b'-') {
+        Sign::Signed
+    } else {
+        return Ok(None);
+    };
+
+    let hours: u8 = scanner.number(2_usize)? as u8;
+
+    let extended: bool = scanner.eat(b':');
+
+    let (mut minutes, mut seconds): (u8, u8) = (0_u8, 0_u8);
+
+    if extended || scanner.digits_ahead() > 0_usize {
+        minutes = scanner.number(2_usize)? as u8;
+
+        if (extended && scanner.eat(b':')) || (!extended && scanner.digits_ahead() > 0_usize) {
+            seconds = scanner.number(2_usize)? as u8;
+        }
+    }
+
+    return Ok(Some(Zone::try_new(sign, hours, minutes, seconds)?));
+}
+
+fn format_year(year: u64) -> String {
+    if year > LAST_BASIC_YEAR {
+        return format!("+{year}");
+    }
+
+    return format!("{year:04}");
+}
+
+fn format_date(date: &Date, representation: Representation, notation: Notation) -> Result<String, Error> {
+    let date: Date = date.to_view(CalendarView::Gregorian)?;
+
+    let separator: &str = match notation {
+        Notation::Extended => "-",
+        Notation::Basic => ""
+    };
+
+    return Ok(match representation {
+        Representation::Calendar => format!("{}{separator}{:02}{separator}{:02}", format_year(date.year), date.month, date.day),
+        Representation::Ordinal => format!("{}{separator}{:03}", format_year(date.year), date.ordinal()),
+        Representation::Week => {
+            let (year, week, week_day): (u64, u8, Week) = date.iso_week()?;
+
+            format!("{}{separator}W{week:02}{separator}{}", format_year(year), week_day.index())
+        }
+    });
+}
+
+fn format_time(time: &Time, notation: Notation) -> String {
+    let separator: &str = match notation {
+        Notation::Extended => ":",
+        Notation::Basic => ""
+    };
+
+    let mut text: String = format!("{:02}{separator}{:02}{separator}{:02}", time.hours, time.minutes, time.seconds);
+
+    if time.nanoseconds > 0_u32 {
+        text.push('.');
+        text.push_str(format!("{:09}", time.nanoseconds).trim_end_matches('0'));
+    }
+
+    text.push_str(&time.time_zone.to_iso8601(notation));
+
+    return text;
+}
+
+impl Zone {
+    pub fn parse_iso8601(text: &str) -> Result<Zone, Error> {
+        let mut scanner: Scanner = Scanner::new(text);
+
+        let time_zone: Zone = parse_zone(&mut scanner)?.ok_or(scanner.error())?;
+
+        scanner.finish()?;
+
+        return Ok(time_zone);
+    }
+
+    // Нулевое смещение записывается как "Z", отрицательный ноль как "-00:00".
+    pub fn to_iso8601(&self, notation: Notation) -> String {
+        if self.sign == Sign::Unsigned && self.to_seconds() == 0_u32 {
+            return String::from("Z");
+        }
+
+        let (sign, separator): (char, &str) = (
+            match self.sign {
+                Sign::Signed => '-',
+                Sign::Unsigned => '+'
+            },
+            match notation {
+                Notation::Extended => ":",
+                Notation::Basic => ""
+            }
+        );
+
+        if self.seconds > 0_u8 {
+            return format!("{sign}{:02}{separator}{:02}{separator}{:02}", self.hours, self.minutes, self.seconds);
+        }
+
+        return format!("{sign}{:02}{separator}{:02}", self.hours, self.minutes);
+    }
+}
+
+impl Time {
+    // Время суток с необязательным "T" в начале, без смещения в тексте берётся time_zone, unix_time отсчитывается от 1970-01-01.
+    pub fn parse_iso8601(text: &str, time_zone: Zone) -> Result<Time, Error> {
+        let mut scanner: Scanner = Scanner::new(text);
+
+        scanner.eat(b'T');
+
+        let (hours, minutes, seconds, nanoseconds): (u8, u8, u8, u32) = parse_time(&mut scanner)?;
+
+        let time_zone: Zone = parse_zone(&mut scanner)?.unwrap_or(time_zone);
+
+        scanner.finish()?;
+
+        let local_seconds: u128 = hours as u128 * SECONDS_IN_HOUR + minutes as u128 * SECONDS_IN_MINUTE + seconds as u128;
+
+        return Ok(Time::from_seconds(local_seconds as i128, nanoseconds, time_zone));
+    }
+
+    pub fn to_iso8601(&self, notation: Notation) -> String {
+        return format_time(self, notation);
+    }
+}
+
+impl Date {
+    // Дата григорианского календаря в полночь time_zone.
+    pub fn parse_iso8601(text: &str, time_zone: Zone) -> Result<Date, Error> {
+        let mut scanner: Scanner = Scanner::new(text);
+
+        let era_days: u128 = parse_date(&mut scanner)?;
+
+        scanner.finish()?;
+
+        return gregorian_date(era_days, time_zone);
+    }
+
+    // ISO 8601 использует григорианский календарь: дата других календарей сначала переводится в него.
+    pub fn to_iso8601(&self, representation: Representation, notation: Notation) -> Result<String, Error> {
+        return format_date(self, representation, notation);
+    }
+}
+
+impl DateTime {
+    // Дата и время через "T", смещение в тексте важнее time_zone.
+    pub fn parse_iso8601(text: &str, time_zone: Zone) -> Result<DateTime, Error> {
+        let mut scanner: Scanner = Scanner::new(text);
+
+        let era_days: u128 = parse_date(&mut scanner)?;
+
+        scanner.expect(b'T')?;
+
+        let (hours, minutes, seconds, nanoseconds): (u8, u8, u8, u32) = parse_time(&mut scanner)?;
+
+        let time_zone: Zone = parse_zone(&mut scanner)?.unwrap_or(time_zone);
+
+        scanner.finish()?;
+
+        let date: Date = gregorian_date(era_days, time_zone)?;
+
+        return DateTime::new(CalendarView::Gregorian, date.year, date.month, date.day, hours, minutes, seconds, nanoseconds, time_zone);
+    }
+
+    pub fn to_iso8601(&self, representation: Representation, notation: Notation) -> Result<String, Error> {
+        return Ok(format!("{}T{}", format_date(&self.date, representation, notation)?, format_time(&self.time, notation)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Date, DateTime, Error, Notation, Representation, Sign, Time, Zone
+    };
+
+    #[test]
+    fn test_iso8601_date_representations() {
+        let time_zone: Zone = Zone { sign: Sign::Signed, hours: 8_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        let date: Date = Date::new(CalendarView::Gregorian, 2024, 3, 1, time_zone).unwrap();
+
+        for (representation, extended, basic) in [
+            (Representation::Calendar, "2024-03-01", "20240301"),
+            (Representation::Ordinal, "2024-061", "2024061"),
+            (Representation::Week, "2024-W09-5", "2024W095")
+        ] {
+            assert_eq!(date.to_iso8601(representation, Notation::Extended).unwrap(), extended);
+            assert_eq!(date.to_iso8601(representation, Notation::Basic).unwrap(), basic);
+            assert!(Date::parse_iso8601(extended, time_zone).unwrap().same_civil_fields(&date));
+            assert!(Date::parse_iso8601(basic, time_zone).unwrap().same_civil_fields(&date));
+        }
+
+        // Недельный год отличается от календарного около границы года.
+        for ((year, month, day), week) in [
+            ((2008, 12, 29), "2009-W01-1"),
+            ((2010, 1, 3), "2009-W53-7"),
+            ((2021, 1, 1), "2020-W53-5"),
+            ((1, 1, 1), "0001-W01-1")
+        ] {
+            let date: Date = Date::new(CalendarView::Gregorian, year, month, day, time_zone).unwrap();
+
+            assert_eq!(date.to_iso8601(Representation::Week, Notation::Extended).unwrap(), week);
+            assert!(Date::parse_iso8601(week, time_zone).unwrap().same_civil_fields(&date));
+        }
+
+        let mut date: Date = Date::new(CalendarView::Gregorian, 1999, 12, 1, time_zone).unwrap();
+
+        while date.year() < 2030 {
+            for representation in [Representation::Calendar, Representation::Ordinal, Representation::Week] {
+                for notation in [Notation::Extended, Notation::Basic] {
+                    let text: String = date.to_iso8601(representation, notation).unwrap();
+
+                    assert!(Date::parse_iso8601(&text, time_zone).unwrap().same_civil_fields(&date), "{text}");
+                }
+            }
+
+            date = date.checked_add_days(1).unwrap();
+        }
+
+        let expanded: Date = Date::new(CalendarView::Gregorian, 12345, 6, 7, time_zone).unwrap();
+
+        for notation in [Notation::Extended, Notation::Basic] {
+            for representation in [Representation::Calendar, Representation::Ordinal] {
+                let text: String = expanded.to_iso8601(representation, notation).unwrap();
+
+                assert!(text.starts_with("+12345"));
+                assert!(Date::parse_iso8601(&text, time_zone).unwrap().same_civil_fields(&expanded), "{text}");
+            }
+        }
+
+        let julian: Date = Date::new(CalendarView::Julian, 2024, 2, 16, time_zone).unwrap();
+
+        assert_eq!(julian.to_iso8601(Representation::Calendar, Notation::Extended).unwrap(), "2024-02-29");
+    }
+
+    #[test]
+    fn test_iso8601_time_and_zone() {
+        let india: Zone = Zone { sign: Sign::Unsigned, hours: 5_u8, minutes: 30_u8, seconds: 0_u8 };
+        let pacific: Zone = Zone { sign: Sign::Signed, hours: 8_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        let time: Time = Time::parse_iso8601("12:30:15.25+05:30", Zone::default()).unwrap();
+
+        assert_eq!((time.hours, time.minutes, time.seconds, time.nanoseconds, time.time_zone), (12, 30, 15, 250_000_000, india));
+        assert_eq!(time.unix_time, 7 * 3600 + 15);
+        assert_eq!(time.to_iso8601(Notation::Extended), "12:30:15.25+05:30");
+        assert_eq!(time.to_iso8601(Notation::Basic), "123015.25+0530");
+
+        let time: Time = Time::parse_iso8601("T123015,1234567891-0800", Zone::default()).unwrap();
+
+        assert_eq!((time.seconds, time.nanoseconds, time.time_zone), (15, 123_456_789, pacific));
+        assert_eq!(Time::parse_iso8601("12:30", pacific).unwrap().to_iso8601(Notation::Extended), "12:30:00-08:00");
+        assert_eq!(Time::parse_iso8601("1230Z", pacific).unwrap().to_iso8601(Notation::Extended), "12:30:00Z");
+
+        assert_eq!(Zone::parse_iso8601("-08"), Ok(pacific));
+        assert_eq!(Zone::parse_iso8601("+0530"), Ok(india));
+        assert_eq!(Zone::parse_iso8601("-00:00").unwrap().to_iso8601(Notation::Extended), "-00:00");
+        assert_eq!(Zone::parse_iso8601("+01:02:03").unwrap().to_iso8601(Notation::Basic), "+010203");
+
+        assert_eq!(Zone::parse_iso8601(""), Err(Error::InvalidFormat(0)));
+        assert_eq!(Zone::parse_iso8601("+24:00"), Err(Error::InvalidZone));
+        assert_eq!(Zone::parse_iso8601("+05:3"), Err(Error::InvalidFormat(5)));
+        assert_eq!(Time::parse_iso8601("24:00", pacific), Err(Error::InvalidHour(24)));
+        assert_eq!(Time::parse_iso8601("12:60", pacific), Err(Error::InvalidMinute(60)));
+        assert_eq!(Time::parse_iso8601("23:59:60", pacific), Err(Error::InvalidSecond(60)));
+        assert_eq!(Time::parse_iso8601("12:30:15.", pacific), Err(Error::InvalidFormat(9)));
+        assert_eq!(Time::parse_iso8601("12:30 ", pacific), Err(Error::InvalidFormat(5)));
+    }
+
+    #[test]
+    fn test_iso8601_date_time() {
+        let pacific: Zone = Zone { sign: Sign::Signed, hours: 8_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        let date_time: DateTime = DateTime::new(CalendarView::Gregorian, 2024, 3, 1, 12, 30, 0, 500_000_000, pacific).unwrap();
+
+        for (text, notation) in [("2024-03-01T12:30:00.5-08:00", Notation::Extended), ("20240301T123000.5-0800", Notation::Basic)] {
+            assert_eq!(date_time.to_iso8601(Representation::Calendar, notation).unwrap(), text);
+            assert_eq!(DateTime::parse_iso8601(text, Zone::default()), Ok(date_time));
+        }
+
+        assert_eq!(date_time.to_iso8601(Representation::Week, Notation::Extended).unwrap(), "2024-W09-5T12:30:00.5-08:00");
+        assert_eq!(DateTime::parse_iso8601("2024-061T12:30:00.5", pacific), Ok(date_time));
+        assert_eq!(DateTime::parse_iso8601("2024-03-01T20:30:00.5Z", pacific).unwrap().instant(), date_time.instant());
+
+        for (text, error) in [
+            ("2024-02-30", Error::InvalidDay(30)),
+            ("2024-13-01", Error::InvalidMonth(13)),
+            ("2023-366", Error::InvalidYearDay(366)),
+            ("2021-W53-1", Error::InvalidWeek(53)),
+            ("2024-W09-8", Error::InvalidWeekDay(8)),
+            ("0000-01-01", Error::InvalidYear(0)),
+            ("+1234-06-07", Error::InvalidFormat(1)),
+            ("2024-0301", Error::InvalidFormat(7)),
+            ("2024-03-01x", Error::InvalidFormat(10)),
+            ("24-03-01", Error::InvalidFormat(2))
+        ] {
+            assert_eq!(Date::parse_iso8601(text, pacific), Err(error), "{text}");
+        }
+
+        assert_eq!(DateTime::parse_iso8601("2024-03-01", pacific), Err(Error::InvalidFormat(10)));
+        assert_eq!(DateTime::parse_iso8601("2024-03-01T12:30+25:00", pacific), Err(Error::InvalidZone));
+    }
+}
@@ -0,0 +1,330 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+use crate::types::{
+    error::{Error},
+    format::{
+        scanner::{Scanner}
+    },
+    data::{
+        date::{Date},
+        date_time::{DateTime},
+        zone::{Sign, Zone}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                constants::{
+                    week::{Week, DAYS_IN_WEEK},
+                    months::{Months, MONTHS_IN_YEAR}
+                },
+                traits::{
+                    day_of_week::{RataDie}
+                }
+            }
+        }
+    }
+};
+
+// Устаревшие названия часовых поясов RFC 5322 (раздел 4.3).
+const ZONE_ABBREVIATIONS: [(&str, Sign, u8); 10] = [
+    ("UT", Sign::Unsigned, 0_u8), ("GMT", Sign::Unsigned, 0_u8),
+    ("EST", Sign::Signed, 5_u8), ("EDT", Sign::Signed, 4_u8),
+    ("CST", Sign::Signed, 6_u8), ("CDT", Sign::Signed, 5_u8),
+    ("MST", Sign::Signed, 7_u8), ("MDT", Sign::Signed, 6_u8),
+    ("PST", Sign::Signed, 8_u8), ("PDT", Sign::Signed, 7_u8)
+];
+
+// Трёхбуквенное сокращение месяца (1 - январь, ...).
+pub(crate) fn parse_month(scanner: &mut Scanner, ignore_case: bool) -> Result<u8, Error> {
+    for month in 1_u8..=MONTHS_IN_YEAR {
+        let name: &str = &Months::from(month).name()[..3_usize];
+
+        if (ignore_case && scanner.eat_ignore_case(name)) || scanner.eat_str(name) {
+            return Ok(month);
+        }
+    }
+
+    return Err(scanner.error());
+}
+
+// Полное название дня недели или его трёхбуквенное сокращение (1 - понедельник, ...).
+pub(crate) fn parse_week_day(scanner: &mut Scanner, full: bool, ignore_case: bool) -> Result<u8, Error> {
+    for week_day in 1_u8..=DAYS_IN_WEEK {
+        let name: &str = if full { Week::from(week_day).name() } else { &Week::from(week_day).name()[..3_usize] };
+
+        if (ignore_case && scanner.eat_ignore_case(name)) || scanner.eat_str(name) {
+            return Ok(week_day);
+        }
+    }
+
+    return Err(scanner.error());
+}
+
+// Сверяет указанный в тексте день недели с датой.
+pub(crate) fn check_week_day(date_time: &DateTime, week_day: Option<(u8, usize)>) -> Result<(), Error> {
+    if let Some((week_day, position)) = week_day {
+        if <Date as RataDie>::week_day(&date_time.date).index() != week_day {
+            return Err(Error::ConflictingField(position));
+        }
+    }
+
+    return Ok(());
+}
+
+// CFWS: пробелы и комментарии в скобках (могут быть вложенными, "\" экранирует следующий символ).
+fn skip_comments(scanner: &mut Scanner) -> Result<(), Error> {
+    scanner.skip_whitespace();
+
+    while scanner.eat(b'(') {
+        let mut depth: usize = 1_usize;
+
+        while depth > 0_usize {
+            match scanner.next_byte() {
+                Some(b'(') => depth += 1_usize,
+                Some(b')') => depth -= 1_usize,
+                Some(b'\\') => { scanner.next_byte(); },
+                Some(_) => {},
+                None => return Err(scanner.error())
+            }
+        }
+
+        scanner.skip_whitespace();
+    }
+
+    return Ok(());
+}
+
+fn parse_zone(scanner: &mut Scanner) -> Result<Zone, Error> {
+    let sign: Option<Sign> = if scanner.eat(b'+') {
+        Some(Sign::Unsigned)
+    } else if scanner.eat(b'-') {
+        Some(Sign::Signed)
+    } else {
+        None
+    };
+
+    if let Some(sign) = sign {
+        let (hours, minutes): (u64, u64) = (scanner.number(2_usize)?, scanner.number(2_usize)?);
+
+        return Zone::try_new(sign, hours as u8, minutes as u8, 0_u8);
+    }
+
+    for (name, sign, hours) in ZONE_ABBREVIATIONS {
+        if scanner.eat_ignore_case(name) {
+            return Ok(Zone { sign: sign, hours: hours, minutes: 0_u8, seconds: 0_u8 });
+        }
+    }
+
+    // Военные пояса из одной буквы (кроме "J") RFC 5322 предписывает считать равными "-0000".
+    match scanner.peek() {
+        Some(letter) if letter.is_ascii_alphabetic() && !letter.eq_ignore_ascii_case(&b'J') => {
+            scanner.next_byte();
+            return Ok(Zone { sign: Sign::Signed, hours: 0_u8, minutes: 0_u8, seconds: 0_u8 });
+        },
+        _ => return Err(scanner.error())
+    }
+}
+
+// Две цифры года: до 50 - 2000-е, иначе 1900-е, три цифры - от 1900 (RFC 5322, раздел 4.3).
+fn parse_year(scanner: &mut Scanner) -> Result<u64, Error> {
+    let digits: usize = scanner.digits_ahead();
+
+    let year: u64 = scanner.number_up_to(20_usize)?;
+
+    return Ok(match digits {
+        2_usize if year < 50_u64 => year + 2000_u64,
+        2_usize | 3_usize => year + 1900_u64,
+        1_usize => return Err(Error::InvalidFormat(scanner.position() - 1_usize)),
+        _ => year
+    });
+}
+
+impl Zone {
+    // UT, GMT и пояса Северной Америки из RFC 5322: EST, EDT, CST, CDT, MST, MDT, PST, PDT.
+    pub fn from_abbreviation(name: &str) -> Option<Zone> {
+        return ZONE_ABBREVIATIONS
+            .iter()
+            .find(|(abbreviation, _, _)| abbreviation.eq_ignore_ascii_case(name))
+            .map(|&(_, sign, hours)| Zone { sign: sign, hours: hours, minutes: 0_u8, seconds: 0_u8 });
+    }
+}
+
+impl DateTime {
+    // "Fri, 01 Mar 2024 14:05:00 +0000": день недели и секунды необязательны, указанный день недели сверяется с датой.
+    pub fn parse_rfc2822(text: &str) -> Result<DateTime, Error> {
+        let mut scanner: Scanner = Scanner::new(text);
+
+        skip_comments(&mut scanner)?;
+
+        let mut week_day: Option<(u8, usize)> = None;
+
+        if matches!(scanner.peek(), Some(letter) if letter.is_ascii_alphabetic()) {
+            let position: usize = scanner.position();
+
+            week_day = Some((parse_week_day(&mut scanner, false, true)?, position));
+
+            skip_comments(&mut scanner)?;
+            scanner.expect(b',')?;
+            skip_comments(&mut scanner)?;
+        }
+
+        let day: u8 = scanner.number_up_to(2_usize)? as u8;
+
+        skip_comments(&mut scanner)?;
+
+        let month: u8 = parse_month(&mut scanner, true)?;
+
+        skip_comments(&mut scanner)?;
+
+        let year: u64 = parse_year(&mut scanner)?;
+
+        skip_comments(&mut scanner)?;
+
+        let hours: u8 = scanner.number(2_usize)? as u8;
+
+        skip_comments(&mut scanner)?;
+        scanner.expect(b':')?;
+        skip_comments(&mut scanner)?;
+
+        let minutes: u8 = scanner.number(2_usize)? as u8;
+
+        skip_comments(&mut scanner)?;
+
+        let seconds: u8 = if scanner.eat(b':') {
+            skip_comments(&mut scanner)?;
+            scanner.number(2_usize)? as u8
+        } else {
+            0_u8
+        };
+
+        skip_comments(&mut scanner)?;
+
+        let time_zone: Zone = parse_zone(&mut scanner)?;
+
+        skip_comments(&mut scanner)?;
+        scanner.finish()?;
+
+        let date_time: DateTime = DateTime::new(CalendarView::Gregorian, year, month, day, hours, minutes, seconds, 0_u32, time_zone)?;
+
+        check_week_day(&date_time, week_day)?;
+
+        return Ok(date_time);
+    }
+
+    // Дата переводится в григорианский календарь, смещение с секундами в RFC 2822 не записать.
+    pub fn to_rfc2822(&self) -> Result<String, Error> {
+        let date_time: DateTime = self.to_view(CalendarView::Gregorian)?;
+
+        let time_zone: Zone = date_time.time_zone();
+
+        if time_zone.seconds > 0_u8 {
+            return Err(Error::InvalidZone);
+        }
+
+        return Ok(format!(
+            "{week_day}, {dd:02} {month} {yyyy:04} {hours:02}:{minutes:02}:{seconds:02} {sign}{zone_hours:02}{zone_minutes:02}",
+            week_day = &<Date as RataDie>::week_day(&date_time.date).name()[..3_usize],
+            dd = date_time.day(), month = &date_time.month().name()[..3_usize], yyyy = date_time.year(),
+            hours = date_time.hours(), minutes = date_time.minutes(), seconds = date_time.seconds(),
+            sign = match time_zone.sign { Sign::Signed => '-', Sign::Unsigned => '+' },
+            zone_hours = time_zone.hours, zone_minutes = time_zone.minutes
+        ));
+    }
+}
+
+impl Date {
+    pub fn parse_rfc2822(text: &str) -> Result<Date, Error> {
+        return Ok(DateTime::parse_rfc2822(text)?.date);
+    }
+
+    pub fn to_rfc2822(&self) -> Result<String, Error> {
+        return DateTime::of(*self).to_rfc2822();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Date, DateTime, Error, Sign, Zone
+    };
+
+    #[test]
+    fn test_rfc2822() {
+        let utc: Zone = Zone::default();
+
+        let date_time: DateTime = DateTime::new(CalendarView::Gregorian, 2024, 3, 1, 14, 5, 0, 0, utc).unwrap();
+
+        assert_eq!(date_time.to_rfc2822().unwrap(), "Fri, 01 Mar 2024 14:05:00 +0000");
+        assert_eq!(DateTime::parse_rfc2822("Fri, 01 Mar 2024 14:05:00 +0000"), Ok(date_time));
+
+        // 1 марта 2024 года - пятница.
+        assert_eq!(DateTime::parse_rfc2822("Tue, 01 Mar 2024 14:05:00 +0000"), Err(Error::ConflictingField(0)));
+
+        for text in [
+            "1 Mar 2024 14:05 GMT",
+            "1 mar 24 14:05:00 UT",
+            " (comment (nested \\) )) fri , 1 Mar 2024 09 : 05 : 00 EST (Eastern) ",
+            "Fri, 01 Mar 2024 06:05:00 PST",
+            "Fri, 01 Mar 2024 19:35:00 +0530"
+        ] {
+            assert_eq!(DateTime::parse_rfc2822(text).map(|value| value.instant()), Ok(date_time.instant()), "{text}");
+        }
+
+        let pacific: DateTime = DateTime::parse_rfc2822("Fri, 01 Mar 2024 07:05:00 PDT").unwrap();
+
+        assert_eq!(pacific.time_zone(), Zone { sign: Sign::Signed, hours: 7_u8, minutes: 0_u8, seconds: 0_u8 });
+        assert_eq!(pacific.to_rfc2822().unwrap(), "Fri, 01 Mar 2024 07:05:00 -0700");
+        assert_eq!(DateTime::parse_rfc2822("1 Mar 2024 14:05 A").unwrap().time_zone(), Zone { sign: Sign::Signed, hours: 0_u8, minutes: 0_u8, seconds: 0_u8 });
+        assert_eq!(DateTime::parse_rfc2822("1 Mar 2024 14:05 -0000").unwrap().to_rfc2822().unwrap(), "Fri, 01 Mar 2024 14:05:00 -0000");
+        assert_eq!(DateTime::parse_rfc2822("1 Mar 99 14:05 GMT").map(|value| value.year()), Ok(1999));
+
+        assert_eq!(Zone::from_abbreviation("cdt"), Some(Zone { sign: Sign::Signed, hours: 5_u8, minutes: 0_u8, seconds: 0_u8 }));
+        assert_eq!(Zone::from_abbreviation("MSK"), None);
+
+        let julian: Date = Date::new(CalendarView::Julian, 2024, 2, 17, utc).unwrap();
+
+        assert_eq!(julian.to_rfc2822().unwrap(), "Fri, 01 Mar 2024 00:00:00 +0000");
+        assert_eq!(Date::parse_rfc2822("Fri, 01 Mar 2024 00:00:00 +0000").unwrap(), julian);
+
+        for (text, error) in [
+            ("30 Feb 2024 14:05 GMT", Error::InvalidDay(30)),
+            ("1 Mar 2024 24:05 GMT", Error::InvalidHour(24)),
+            ("1 Mar 2024 14:05 +2500", Error::InvalidZone),
+            ("1 Mar 2024 14:05 J", Error::InvalidFormat(17)),
+            ("1 Mar 2024 14:05", Error::InvalidFormat(16)),
+            ("1 March 2024 14:05 GMT", Error::InvalidFormat(5)),
+            ("Fri 1 Mar 2024 14:05 GMT", Error::InvalidFormat(4)),
+            ("1 Mar 2024 14:05 GMT (open", Error::InvalidFormat(26))
+        ] {
+            assert_eq!(DateTime::parse_rfc2822(text), Err(error), "{text}");
+        }
+
+        assert_eq!(DateTime::new(CalendarView::Gregorian, 2024, 3, 1, 0, 0, 0, 0, Zone { sign: Sign::Unsigned, hours: 0_u8, minutes: 0_u8, seconds: 30_u8 }).unwrap().to_rfc2822(), Err(Error::InvalidZone));
+    }
+}
@@ -0,0 +1,333 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+use crate::types::{
+    error::{Error},
+    format::{
+        scanner::{Scanner}
+    },
+    data::{
+        date::{Date},
+        date_time::{DateTime},
+        time::{Time},
+        instant::{Instant},
+        zone::{Sign, Zone}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                constants::{
+                    hours::{HOURS_IN_A_DAY},
+                    minutes::{MINUTES_IN_HOUR},
+                    seconds::{SECONDS_IN_DAY, SECONDS_IN_HOUR, SECONDS_IN_MINUTE, NANOSECONDS_IN_SECOND}
+                }
+            }
+        }
+    }
+};
+
+// Секунда координации (":60") возможна только в последнюю секунду суток UTC, шкала unix time её не содержит.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeapSecond {
+    // Error::InvalidSecond(60).
+    #[default]
+    Reject,
+    // Последняя наносекунда предыдущей секунды (":59.999999999"), порядок моментов сохраняется.
+    Clamp,
+    // Первая секунда следующей минуты с той же дробной частью.
+    Roll
+}
+
+// Дробная часть секунд при записи: Auto - наименьшее число цифр без конечных нулей (нет дробной части при нуле),
+// Digits(n) - ровно n цифр, сверх наносекунд дописываются нули.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fraction {
+    #[default]
+    Auto,
+    Digits(u8)
+}
+
+const LAST_YEAR: u64 = 9999_u64;
+const LEAP_SECOND: u8 = 60_u8;
+
+// partial-time: "hh:mm:ss" и необязательная дробная часть через ".".
+fn parse_partial_time(scanner: &mut Scanner) -> Result<(u8, u8, u8, u32), Error> {
+    let hours: u8 = scanner.number(2_usize)? as u8;
+    scanner.expect(b':')?;
+    let minutes: u8 = scanner.number(2_usize)? as u8;
+    scanner.expect(b':')?;
+    let seconds: u8 = scanner.number(2_usize)? as u8;
+
+    let nanoseconds: u32 = if scanner.eat(b'.') { scanner.fraction()? } else { 0_u32 };
+
+    if hours >= HOURS_IN_A_DAY {
+        return Err(Error::InvalidHour(hours));
+    } else if minutes >= MINUTES_IN_HOUR {
+        return Err(Error::InvalidMinute(minutes));
+    } else if seconds > LEAP_SECOND {
+        return Err(Error::InvalidSecond(seconds));
+    }
+
+    return Ok((hours, minutes, seconds, nanoseconds));
+}
+
+// time-offset: "Z" (или "z") либо "+hh:mm"/"-hh:mm", "-00:00" - смещение неизвестно, хранится как Sign::Signed с нулём.
+fn parse_offset(scanner: &mut Scanner) -> Result<Zone, Error> {
+    if scanner.eat(b'Z') || scanner.eat(b'z') {
+        return Ok(Zone::default());
+    }
+
+    let sign: Sign = if scanner.eat(b'+') {
+        Sign::Unsigned
+    } else if scanner.eat(b'-') {
+        Sign::Signed
+    } else {
+        return Err(scanner.error());
+    };
+
+    let hours: u8 = scanner.number(2_usize)? as u8;
+    scanner.expect(b':')?;
+    let minutes: u8 = scanner.number(2_usize)? as u8;
+
+    return Zone::try_new(sign, hours, minutes, 0_u8);
+}
+
+// Местные секунды суток и наносекунды с учётом политики секунды координации.
+fn resolve_seconds(hours: u8, minutes: u8, seconds: u8, nanoseconds: u32, time_zone: Zone, policy: LeapSecond) -> Result<(i128, u32), Error> {
+    let local_seconds: i128 = (hours as u128 * SECONDS_IN_HOUR + minutes as u128 * SECONDS_IN_MINUTE + seconds as u128) as i128;
+
+    if seconds != LEAP_SECOND {
+        return Ok((local_seconds, nanoseconds));
+    }
+
+    // ":60" допустима только вместо 23:59:60 UTC.
+    if (local_seconds - 1_i128 - time_zone.offset()).rem_euclid(SECONDS_IN_DAY as i128) != SECONDS_IN_DAY as i128 - 1_i128 {
+        return Err(Error::InvalidSecond(seconds));
+    }
+
+    return match policy {
+        LeapSecond::Reject => Err(Error::InvalidSecond(seconds)),
+        LeapSecond::Clamp => Ok((local_seconds - 1_i128, NANOSECONDS_IN_SECOND - 1_u32)),
+        LeapSecond::Roll => Ok((local_seconds, nanoseconds))
+    };
+}
+
+fn format_fraction(nanoseconds: u32, fraction: Fraction) -> String {
+    let digits: String = format!("{nanoseconds:09}");
+
+    return match fraction {
+        Fraction::Auto if nanoseconds == 0_u32 => String::new(),
+        Fraction::Auto => format!(".{}", digits.trim_end_matches('0')),
+        Fraction::Digits(0_u8) => String::new(),
+        Fraction::Digits(width) if width <= 9_u8 => format!(".{}", &digits[..width as usize]),
+        Fraction::Digits(width) => format!(".{digits}{}", "0".repeat(width as usize - 9_usize))
+    };
+}
+
+// Смещение с секундами в RFC 3339 не записать.
+fn format_offset(time_zone: Zone) -> Result<String, Error> {
+    if time_zone.seconds > 0_u8 {
+        return Err(Error::InvalidZone);
+    } else if time_zone.sign == Sign::Unsigned && time_zone.to_seconds() == 0_u32 {
+        return Ok(String::from("Z"));
+    }
+
+    return Ok(format!("{}{:02}:{:02}", match time_zone.sign { Sign::Signed => '-', Sign::Unsigned => '+' }, time_zone.hours, time_zone.minutes));
+}
+
+fn format_time(time: &Time, fraction: Fraction) -> Result<String, Error> {
+    return Ok(format!("{:02}:{:02}:{:02}{}{}", time.hours, time.minutes, time.seconds, format_fraction(time.nanoseconds, fraction), format_offset(time.time_zone)?));
+}
+
+impl DateTime {
+    // "2024-03-01T14:05:00.123456Z": строго по RFC 3339 (разделитель "T" или "t"), григорианский календарь.
+    pub fn parse_rfc3339(text: &str, policy: LeapSecond) -> Result<DateTime, Error> {
+        let mut scanner: Scanner = Scanner::new(text);
+
+        let year: u64 = scanner.number(4_usize)?;
+        scanner.expect(b'-')?;
+        let month: u8 = scanner.number(2_usize)? as u8;
+        scanner.expect(b'-')?;
+        let day: u8 = scanner.number(2_usize)? as u8;
+
+        if !scanner.eat(b'T') && !scanner.eat(b't') {
+            return Err(scanner.error());
+        }
+
+        let (hours, minutes, seconds, nanoseconds): (u8, u8, u8, u32) = parse_partial_time(&mut scanner)?;
+
+        let time_zone: Zone = parse_offset(&mut scanner)?;
+
+        scanner.finish()?;
+
+        if year == 0_u64 {
+            return Err(Error::InvalidYear(year));
+        }
+
+        let (local_seconds, nanoseconds): (i128, u32) = resolve_seconds(hours, minutes, seconds, nanoseconds, time_zone, policy)?;
+
+        let midnight: Date = Date::new(CalendarView::Gregorian, year, month, day, time_zone)?;
+
+        let instant: Instant = Instant::new(midnight.unix_time().checked_add(local_seconds).ok_or(Error::Overflow)?, nanoseconds)?;
+
+        return DateTime::try_from(CalendarView::Gregorian, instant, time_zone);
+    }
+
+    // Дата переводится в григорианский календарь, год должен уместиться в четыре цифры.
+    pub fn to_rfc3339(&self, fraction: Fraction) -> Result<String, Error> {
+        let date_time: DateTime = self.to_view(CalendarView::Gregorian)?;
+
+        if date_time.year() > LAST_YEAR {
+            return Err(Error::InvalidYear(date_time.year()));
+        }
+
+        return Ok(format!("{:04}-{:02}-{:02}T{}", date_time.year(), date_time.date.month, date_time.day(), format_time(&date_time.time, fraction)?));
+    }
+}
+
+impl Date {
+    pub fn parse_rfc3339(text: &str, policy: LeapSecond) -> Result<Date, Error> {
+        return Ok(DateTime::parse_rfc3339(text, policy)?.date);
+    }
+
+    pub fn to_rfc3339(&self, fraction: Fraction) -> Result<String, Error> {
+        return DateTime::of(*self).to_rfc3339(fraction);
+    }
+}
+
+impl Time {
+    // full-time: "14:05:00.123456+05:30", unix_time отсчитывается от 1970-01-01.
+    pub fn parse_rfc3339(text: &str, policy: LeapSecond) -> Result<Time, Error> {
+        let mut scanner: Scanner = Scanner::new(text);
+
+        let (hours, minutes, seconds, nanoseconds): (u8, u8, u8, u32) = parse_partial_time(&mut scanner)?;
+
+        let time_zone: Zone = parse_offset(&mut scanner)?;
+
+        scanner.finish()?;
+
+        let (local_seconds, nanoseconds): (i128, u32) = resolve_seconds(hours, minutes, seconds, nanoseconds, time_zone, policy)?;
+
+        return Ok(Time::from_seconds(local_seconds, nanoseconds, time_zone));
+    }
+
+    pub fn to_rfc3339(&self, fraction: Fraction) -> Result<String, Error> {
+        return format_time(self, fraction);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Date, DateTime, Error, Fraction, Instant, LeapSecond, Sign, Time, Zone
+    };
+
+    #[test]
+    fn test_rfc3339_round_trip() {
+        let india: Zone = Zone { sign: Sign::Unsigned, hours: 5_u8, minutes: 30_u8, seconds: 0_u8 };
+        let unknown: Zone = Zone { sign: Sign::Signed, hours: 0_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        for text in [
+            "2024-03-01T14:05:00.123456Z",
+            "2024-03-01T14:05:00Z",
+            "2024-03-01T19:35:00.000000001+05:30",
+            "1969-12-31T23:59:59.5-08:00",
+            "0001-01-01T00:00:00-00:00",
+            "9999-12-31T23:59:59.999999999+23:59"
+        ] {
+            let date_time: DateTime = DateTime::parse_rfc3339(text, LeapSecond::Reject).unwrap();
+
+            assert_eq!(date_time.to_rfc3339(Fraction::Auto).unwrap(), text);
+            assert_eq!(DateTime::parse_rfc3339(&date_time.to_rfc3339(Fraction::Digits(9)).unwrap(), LeapSecond::Reject), Ok(date_time));
+        }
+
+        let date_time: DateTime = DateTime::parse_rfc3339("2024-03-01t19:35:00.123456789+05:30", LeapSecond::Reject).unwrap();
+
+        assert_eq!(date_time.instant(), Instant::new(1_709_301_900, 123_456_789).unwrap());
+        assert_eq!(date_time.time_zone(), india);
+
+        for (fraction, text) in [
+            (Fraction::Digits(0), "2024-03-01T19:35:00+05:30"),
+            (Fraction::Digits(3), "2024-03-01T19:35:00.123+05:30"),
+            (Fraction::Digits(6), "2024-03-01T19:35:00.123456+05:30"),
+            (Fraction::Digits(12), "2024-03-01T19:35:00.123456789000+05:30")
+        ] {
+            assert_eq!(date_time.to_rfc3339(fraction).unwrap(), text);
+        }
+
+        // "-00:00": момент в UTC, местное смещение неизвестно.
+        let unknown_offset: DateTime = DateTime::parse_rfc3339("2024-03-01T14:05:00-00:00", LeapSecond::Reject).unwrap();
+
+        assert_eq!(unknown_offset.time_zone(), unknown);
+        assert_eq!(unknown_offset.instant(), DateTime::parse_rfc3339("2024-03-01T14:05:00Z", LeapSecond::Reject).unwrap().instant());
+
+        let julian: Date = Date::new(CalendarView::Julian, 2024, 2, 17, india).unwrap();
+
+        assert_eq!(julian.to_rfc3339(Fraction::Auto).unwrap(), "2024-03-01T00:00:00+05:30");
+        assert_eq!(Date::parse_rfc3339("2024-03-01T00:00:00+05:30", LeapSecond::Reject), Ok(julian));
+
+        let time: Time = Time::parse_rfc3339("14:05:00.25-00:00", LeapSecond::Reject).unwrap();
+
+        assert_eq!((time.hours, time.nanoseconds, time.time_zone), (14, 250_000_000, unknown));
+        assert_eq!(time.to_rfc3339(Fraction::Digits(4)).unwrap(), "14:05:00.2500-00:00");
+    }
+
+    #[test]
+    fn test_rfc3339_leap_second_and_errors() {
+        let text: &str = "2016-12-31T23:59:60.5Z";
+
+        assert_eq!(DateTime::parse_rfc3339(text, LeapSecond::Reject), Err(Error::InvalidSecond(60)));
+        assert_eq!(DateTime::parse_rfc3339(text, LeapSecond::Clamp).unwrap().to_rfc3339(Fraction::Auto).unwrap(), "2016-12-31T23:59:59.999999999Z");
+        assert_eq!(DateTime::parse_rfc3339(text, LeapSecond::Roll).unwrap().to_rfc3339(Fraction::Auto).unwrap(), "2017-01-01T00:00:00.5Z");
+        assert_eq!(DateTime::parse_rfc3339("2017-01-01T05:29:60+05:30", LeapSecond::Roll).unwrap().to_rfc3339(Fraction::Auto).unwrap(), "2017-01-01T05:30:00+05:30");
+        assert_eq!(DateTime::parse_rfc3339("2016-12-31T22:59:60Z", LeapSecond::Clamp), Err(Error::InvalidSecond(60)));
+        assert_eq!(Time::parse_rfc3339("15:59:60-08:00", LeapSecond::Clamp).map(|time| (time.seconds, time.nanoseconds)), Ok((59, 999_999_999)));
+
+        for (text, error) in [
+            ("2024-03-01 14:05:00Z", Error::InvalidFormat(10)),
+            ("2024-03-01T14:05Z", Error::InvalidFormat(16)),
+            ("2024-03-01T14:05:00", Error::InvalidFormat(19)),
+            ("2024-03-01T14:05:00+0530", Error::InvalidFormat(22)),
+            ("2024-03-01T14:05:00.Z", Error::InvalidFormat(20)),
+            ("2024-3-01T14:05:00Z", Error::InvalidFormat(6)),
+            ("+12024-03-01T14:05:00Z", Error::InvalidFormat(0)),
+            ("0000-03-01T14:05:00Z", Error::InvalidYear(0)),
+            ("2023-02-29T14:05:00Z", Error::InvalidDay(29)),
+            ("2024-03-01T24:00:00Z", Error::InvalidHour(24)),
+            ("2024-03-01T14:05:61Z", Error::InvalidSecond(61)),
+            ("2024-03-01T14:05:00+24:00", Error::InvalidZone)
+        ] {
+            assert_eq!(DateTime::parse_rfc3339(text, LeapSecond::Clamp), Err(error), "{text}");
+        }
+
+        let zone: Zone = Zone { sign: Sign::Unsigned, hours: 0_u8, minutes: 0_u8, seconds: 1_u8 };
+
+        assert_eq!(DateTime::new(CalendarView::Gregorian, 2024, 3, 1, 0, 0, 0, 0, zone).unwrap().to_rfc3339(Fraction::Auto), Err(Error::InvalidZone));
+        assert_eq!(DateTime::new(CalendarView::Gregorian, 10000, 1, 1, 0, 0, 0, 0, Zone::default()).unwrap().to_rfc3339(Fraction::Auto), Err(Error::InvalidYear(10000)));
+    }
+}
@@ -0,0 +1,187 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+use crate::types::{
+    error::{Error},
+    planets::{
+        earth::{
+            calendar::{
+                constants::{
+                    seconds::{NANOSECONDS_IN_SECOND}
+                }
+            }
+        }
+    }
+};
+
+// Разбор текста по байтам, ошибка формата хранит позицию первого неожиданного байта.
+pub(crate) struct Scanner<'a> {
+    bytes: &'a [u8],
+    position: usize
+}
+
+impl<'a> Scanner<'a> {
+    pub(crate) const fn new(text: &'a str) -> Scanner<'a> {
+        return Scanner { bytes: text.as_bytes(), position: 0_usize };
+    }
+
+    pub(crate) const fn position(&self) -> usize {
+        return self.position;
+    }
+
+    pub(crate) const fn error(&self) -> Error {
+        return Error::InvalidFormat(self.position);
+    }
+
+    pub(crate) fn peek(&self) -> Option<u8> {
+        return self.bytes.get(self.position).copied();
+    }
+
+    pub(crate) fn peek_at(&self, offset: usize) -> Option<u8> {
+        return self.bytes.get(self.position + offset).copied();
+    }
+
+    pub(crate) fn next_byte(&mut self) -> Option<u8> {
+        let byte: Option<u8> = self.peek();
+
+        if byte.is_some() {
+            self.position += 1_usize;
+        }
+
+        return byte;
+    }
+
+    pub(crate) fn is_end(&self) -> bool {
+        return self.position == self.bytes.len();
+    }
+
+    pub(crate) fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.position += 1_usize;
+            return true;
+        }
+
+        return false;
+    }
+
+    pub(crate) fn eat_str(&mut self, text: &str) -> bool {
+        if self.bytes[self.position..].starts_with(text.as_bytes()) {
+            self.position += text.len();
+            return true;
+        }
+
+        return false;
+    }
+
+    pub(crate) fn eat_ignore_case(&mut self, text: &str) -> bool {
+        let end: usize = self.position + text.len();
+
+        if end <= self.bytes.len() && self.bytes[self.position..end].eq_ignore_ascii_case(text.as_bytes()) {
+            self.position = end;
+            return true;
+        }
+
+        return false;
+    }
+
+    pub(crate) fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(byte) if byte.is_ascii_whitespace()) {
+            self.position += 1_usize;
+        }
+    }
+
+    pub(crate) fn expect(&mut self, byte: u8) -> Result<(), Error> {
+        if !self.eat(byte) {
+            return Err(self.error());
+        }
+
+        return Ok(());
+    }
+
+    pub(crate) fn finish(&self) -> Result<(), Error> {
+        if !self.is_end() {
+            return Err(self.error());
+        }
+
+        return Ok(());
+    }
+
+    // Количество подряд идущих цифр начиная с текущей позиции (позиция не меняется).
+    pub(crate) fn digits_ahead(&self) -> usize {
+        return self.bytes[self.position..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+    }
+
+    // Ровно width цифр.
+    pub(crate) fn number(&mut self, width: usize) -> Result<u64, Error> {
+        if self.digits_ahead() < width {
+            return Err(Error::InvalidFormat(self.position + self.digits_ahead()));
+        }
+
+        let mut value: u64 = 0_u64;
+
+        for _ in 0..width {
+            value = value
+                .checked_mul(10_u64)
+                .and_then(|value| value.checked_add((self.bytes[self.position] - b'0') as u64))
+                .ok_or(Error::Overflow)?;
+            self.position += 1_usize;
+        }
+
+        return Ok(value);
+    }
+
+    // От одной до max_width цифр.
+    pub(crate) fn number_up_to(&mut self, max_width: usize) -> Result<u64, Error> {
+        let width: usize = self.digits_ahead().min(max_width);
+
+        if width == 0_usize {
+            return Err(self.error());
+        }
+
+        return self.number(width);
+    }
+
+    // Дробная часть секунды: лишние после наносекунд цифры отбрасываются.
+    pub(crate) fn fraction(&mut self) -> Result<u32, Error> {
+        let width: usize = self.digits_ahead();
+
+        if width == 0_usize {
+            return Err(self.error());
+        }
+
+        let (mut nanoseconds, mut scale): (u32, u32) = (0_u32, NANOSECONDS_IN_SECOND);
+
+        for index in 0..width {
+            scale /= 10_u32;
+            nanoseconds += (self.bytes[self.position + index] - b'0') as u32 * scale;
+        }
+
+        self.position += width;
+
+        return Ok(nanoseconds);
+    }
+}
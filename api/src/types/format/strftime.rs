@@ -0,0 +1,332 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+use std::{
+    fmt::{Write}
+};
+
+use crate::types::{
+    error::{Error},
+    data::{
+        date::{Date},
+        date_time::{DateTime},
+        time::{Time},
+        zone::{Zone}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                constants::{
+                    week::{Week, DAYS_IN_WEEK},
+                    months::{Months}
+                },
+                traits::{
+                    day_of_week::{RataDie}
+                }
+            }
+        }
+    }
+};
+
+// Поля, которых нет у одного Time.
+pub(crate) const DATE_SPECIFIERS: &str = "YCyGgmdejUWVuwBbhAaDFKQ";
+
+#[derive(Clone, Copy)]
+enum Padding {
+    Zero,
+    Space,
+    None
+}
+
+fn number(value: u128, width: usize, padding: Padding) -> String {
+    return match padding {
+        Padding::Zero => format!("{value:0width$}"),
+        Padding::Space => format!("{value:width$}"),
+        Padding::None => value.to_string()
+    };
+}
+
+fn offset(date_time: &DateTime, separator: &str) -> String {
+    let time_zone: Zone = date_time.time.time_zone;
+
+    let mut text: String = format!(
+        "{sign}{:02}{separator}{:02}",
+        time_zone.hours, time_zone.minutes,
+        sign = if time_zone.offset() < 0_i128 { '-' } else { '+' }
+    );
+
+    if time_zone.seconds > 0_u8 {
+        text.push_str(&format!("{separator}{:02}", time_zone.seconds));
+    }
+
+    return text;
+}
+
+// Разбор шаблона strftime: "%[флаги]символ", флаги "-" (без выравнивания), "_" (пробелы), "0" (нули), "^" (заглавные буквы).
+// has_date ложно при форматировании одного Time: поля даты в шаблоне тогда считаются ошибкой.
+fn write_pattern<W: Write>(output: &mut W, pattern: &str, date_time: &DateTime, has_date: bool, method: fn(CalendarView, u64, u8, u8) -> Week) -> Result<(), Error> {
+    let mut characters = pattern.char_indices().peekable();
+
+    while let Some((_, character)) = characters.next() {
+        if character != '%' {
+            output.write_char(character).map_err(|_| Error::WriteFailed)?;
+            continue;
+        }
+
+        let (mut padding, mut upper): (Option<Padding>, bool) = (None, false);
+
+        while let Some(&(_, flag)) = characters.peek() {
+            match flag {
+                '-' => padding = Some(Padding::None),
+                '_' => padding = Some(Padding::Space),
+                '0' => padding = Some(Padding::Zero),
+                '^' => upper = true,
+                _ => break
+            }
+
+            characters.next();
+        }
+
+        let (position, specifier): (usize, char) = characters.next().ok_or(Error::InvalidFormat(pattern.len()))?;
+
+        let pad = |default: Padding| -> Padding { return padding.unwrap_or(default); };
+
+        let date: &Date = &date_time.date;
+        let time: &Time = &date_time.time;
+
+        if !has_date && DATE_SPECIFIERS.contains(specifier) {
+            return Err(Error::InvalidFormat(position));
+        }
+
+        let week_day = || -> Week { return method(date.view, date.year, date.month, date.day); };
+
+        let year_day = || -> u128 { return date.ordinal() as u128; };
+
+        // Номер недели года, первая неделя начинается с первого дня недели first (0 - воскресенье, 1 - понедельник).
+        let year_week_from = |first: u8| -> u128 {
+            let shift: u128 = (week_day().index() % DAYS_IN_WEEK + DAYS_IN_WEEK - first) as u128 % DAYS_IN_WEEK as u128;
+
+            return (year_day() - 1_u128 + DAYS_IN_WEEK as u128 - shift) / DAYS_IN_WEEK as u128;
+        };
+
+        let iso_week = || -> Result<(u64, u8), Error> {
+            let (year, week, _): (u64, u8, Week) = date.to_view(CalendarView::Gregorian)?.iso_week()?;
+
+            return Ok((year, week));
+        };
+
+        let twelve_hours: u128 = match time.hours % 12_u8 { 0_u8 => 12_u128, hours => hours as u128 };
+
+        let text: String = match specifier {
+            'Y' => number(date.year as u128, 4_usize, pad(Padding::Zero)),
+            'C' => number(date.year as u128 / 100_u128, 2_usize, pad(Padding::Zero)),
+            'y' => number(date.year as u128 % 100_u128, 2_usize, pad(Padding::Zero)),
+            'G' => number(iso_week()?.0 as u128, 4_usize, pad(Padding::Zero)),
+            'g' => number(iso_week()?.0 as u128 % 100_u128, 2_usize, pad(Padding::Zero)),
+            'm' => number(date.month as u128, 2_usize, pad(Padding::Zero)),
+            'd' => number(date.day as u128, 2_usize, pad(Padding::Zero)),
+            'e' => number(date.day as u128, 2_usize, pad(Padding::Space)),
+            'j' => number(year_day(), 3_usize, pad(Padding::Zero)),
+            'U' => number(year_week_from(0_u8), 2_usize, pad(Padding::Zero)),
+            'W' => number(year_week_from(1_u8), 2_usize, pad(Padding::Zero)),
+            'V' => number(iso_week()?.1 as u128, 2_usize, pad(Padding::Zero)),
+            'u' => number(week_day().index() as u128, 1_usize, pad(Padding::Zero)),
+            'w' => number((week_day().index() % DAYS_IN_WEEK) as u128, 1_usize, pad(Padding::Zero)),
+            'B' => String::from(Months::try_from(date.month)?.name()),
+            'b' | 'h' => Months::try_from(date.month)?.name().chars().take(3_usize).collect(),
+            'A' => String::from(week_day().name()),
+            'a' => week_day().name().chars().take(3_usize).collect(),
+            'H' => number(time.hours as u128, 2_usize, pad(Padding::Zero)),
+            'k' => number(time.hours as u128, 2_usize, pad(Padding::Space)),
+            'I' => number(twelve_hours, 2_usize, pad(Padding::Zero)),
+            'l' => number(twelve_hours, 2_usize, pad(Padding::Space)),
+            'M' => number(time.minutes as u128, 2_usize, pad(Padding::Zero)),
+            'S' => number(time.seconds as u128, 2_usize, pad(Padding::Zero)),
+            'N' => number(time.nanoseconds as u128, 9_usize, pad(Padding::Zero)),
+            'p' => String::from(if time.hours < 12_u8 { "AM" } else { "PM" }),
+            'P' => String::from(if time.hours < 12_u8 { "am" } else { "pm" }),
+            'z' => offset(date_time, ""),
+            ':' if characters.next_if(|&(_, next)| next == 'z').is_some() => offset(date_time, ":"),
+            's' => time.unix_time.to_string(),
+            // Расширения: название календаря и дни эры.
+            'K' => String::from(date.view.name()),
+            'Q' => number(date.era_days, 1_usize, pad(Padding::None)),
+            'D' => { write_pattern(output, "%m/%d/%y", date_time, has_date, method)?; continue; },
+            'F' => { write_pattern(output, "%Y-%m-%d", date_time, has_date, method)?; continue; },
+            'T' => { write_pattern(output, "%H:%M:%S", date_time, has_date, method)?; continue; },
+            'R' => { write_pattern(output, "%H:%M", date_time, has_date, method)?; continue; },
+            'n' => String::from("\n"),
+            't' => String::from("\t"),
+            '%' => String::from("%"),
+            _ => return Err(Error::InvalidFormat(position))
+        };
+
+        if upper {
+            output.write_str(&text.to_uppercase()).map_err(|_| Error::WriteFailed)?;
+        } else {
+            output.write_str(&text).map_err(|_| Error::WriteFailed)?;
+        }
+    }
+
+    return Ok(());
+}
+
+// Числовое значение поля, как его записывает format(): для сверки разобранных полей.
+pub(crate) fn numeric_field(date_time: &DateTime, specifier: char) -> Result<i128, Error> {
+    let mut text: String = String::new();
+
+    write_pattern(&mut text, &format!("%-{specifier}"), date_time, true, <Date as RataDie>::from)?;
+
+    return text.parse::<i128>().map_err(|_| Error::InvalidFormat(1_usize));
+}
+
+impl Date {
+    // День недели по Rata Die, другой алгоритм задаётся в format_with().
+    pub fn format<W: Write>(&self, pattern: &str, output: &mut W) -> Result<(), Error> {
+        return self.format_with(pattern, output, <Date as RataDie>::from);
+    }
+
+    pub fn format_with<W: Write>(&self, pattern: &str, output: &mut W, method: fn(CalendarView, u64, u8, u8) -> Week) -> Result<(), Error> {
+        return write_pattern(output, pattern, &DateTime::of(*self), true, method);
+    }
+}
+
+impl DateTime {
+    pub fn format<W: Write>(&self, pattern: &str, output: &mut W) -> Result<(), Error> {
+        return self.date.format(pattern, output);
+    }
+
+    pub fn format_with<W: Write>(&self, pattern: &str, output: &mut W, method: fn(CalendarView, u64, u8, u8) -> Week) -> Result<(), Error> {
+        return self.date.format_with(pattern, output, method);
+    }
+}
+
+impl Time {
+    // Только поля времени и смещения: поля даты возвращают Error::InvalidFormat.
+    pub fn format<W: Write>(&self, pattern: &str, output: &mut W) -> Result<(), Error> {
+        let date_time: DateTime = DateTime { date: Date::default(), time: *self };
+
+        return write_pattern(output, pattern, &date_time, false, <Date as RataDie>::from);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Date, DateTime, Error, Time, Zone
+    };
+
+    use libc::{
+        c_char, time_t, tm,
+        gmtime_r, strftime
+    };
+
+    use crate::types::{
+        data::{
+            instant::{Instant},
+            zone::{Sign}
+        },
+        planets::{
+            earth::{
+                calendar::{
+                    cutover::{Cutover},
+                    traits::{
+                        day_of_week::{Sakamoto}
+                    }
+                }
+            }
+        }
+    };
+
+    fn format(date_time: &DateTime, pattern: &str) -> Result<String, Error> {
+        let mut text: String = String::new();
+
+        date_time.format(pattern, &mut text)?;
+
+        return Ok(text);
+    }
+
+    #[test]
+    fn test_format_as_libc() {
+        let pattern: &str = "%Y-%m-%d %H:%M:%S %z|%C %y %G %g %j %U %W %V %u %w|%a %A %b %h %B|%e %I %l %k %p %P|%D %F %T %R %s %%";
+
+        let (mut time_struct_libc, mut buffer): (tm, [u8; 256]) = (unsafe { std::mem::zeroed::<tm>() }, [0_u8; 256]);
+
+        let c_pattern: Vec<u8> = pattern.bytes().chain([0_u8]).collect();
+
+        // Шаг в сутки и час с секундами перебирает все дни недели, часы и границы недель.
+        for unix_time in (-2_208_988_800_i64..=4_102_444_800_i64).step_by(90_061_usize) {
+            let time_c: time_t = unix_time as time_t;
+
+            if unsafe { gmtime_r(&time_c, &mut time_struct_libc) }.is_null() {
+                panic!("[ERROR]: Pointer is NULL (gmtime_r)!")
+            }
+
+            let length: usize = unsafe { strftime(buffer.as_mut_ptr() as *mut c_char, buffer.len(), c_pattern.as_ptr() as *const c_char, &time_struct_libc) };
+
+            let date_time: DateTime = DateTime::from(CalendarView::Gregorian, Instant::from_seconds(unix_time as i128), Zone::default());
+
+            assert_eq!(format(&date_time, pattern).unwrap(), std::str::from_utf8(&buffer[..length]).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_format_flags_and_views() {
+        let india: Zone = Zone { sign: Sign::Unsigned, hours: 5_u8, minutes: 30_u8, seconds: 0_u8 };
+
+        let date_time: DateTime = DateTime::new(CalendarView::Gregorian, 2024, 3, 1, 7, 5, 9, 42, india).unwrap();
+
+        assert_eq!(format(&date_time, "%-d/%-m/%Y %_H|%-H|%0e|%^a %^B|%:z|%N").unwrap(), "1/3/2024  7|7|01|FRI MARCH|+05:30|000000042");
+        assert_eq!(format(&date_time, "%K %Q").unwrap(), format!("Gregorian {}", date_time.era_days()));
+
+        let julian: DateTime = date_time.to_view(CalendarView::Julian).unwrap();
+
+        // Поля берутся из календаря даты, ISO неделя - из григорианского.
+        assert_eq!(format(&julian, "%K %F %A %j %V").unwrap(), "Julian 2024-02-17 Friday 048 09");
+
+        let mut text: String = String::new();
+
+        julian.format_with("%A", &mut text, <Date as Sakamoto>::from).unwrap();
+        assert_eq!(text, "Friday");
+
+        let reform: Date = Date::new(CalendarView::Historical(Cutover::ROME), 1582, 10, 15, india).unwrap();
+
+        text.clear();
+        reform.format("%F %j %K", &mut text).unwrap();
+        assert_eq!(text, "1582-10-15 278 Historical");
+
+        text.clear();
+        Time::parse_iso8601("23:30:00-08:00", india).unwrap().format("%I:%M %p %z %s", &mut text).unwrap();
+        assert_eq!(text, "11:30 PM -0800 113400");
+
+        assert_eq!(Time::default().format("%H %d", &mut text), Err(Error::InvalidFormat(4)));
+        assert_eq!(format(&date_time, "%Y-%"), Err(Error::InvalidFormat(4)));
+        assert_eq!(format(&date_time, "%Y %J"), Err(Error::InvalidFormat(4)));
+        assert_eq!(format(&date_time, "%:y"), Err(Error::InvalidFormat(1)));
+    }
+}
@@ -0,0 +1,404 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+use crate::types::{
+    error::{Error},
+    format::{
+        scanner::{Scanner},
+        iso8601::{parse_zone},
+        strftime::{DATE_SPECIFIERS, numeric_field}
+    },
+    data::{
+        date::{Date},
+        date_time::{DateTime},
+        time::{Time},
+        instant::{Instant},
+        zone::{Zone}
+    },
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                constants::{
+                    week::{Week, DAYS_IN_WEEK},
+                    months::{Months, MONTHS_IN_YEAR},
+                    hours::{HOURS_IN_A_DAY},
+                    minutes::{MINUTES_IN_HOUR},
+                    seconds::{SECONDS_IN_HOUR, SECONDS_IN_MINUTE}
+                },
+                functions::{
+                    weeks_in_year
+                }
+            }
+        }
+    }
+};
+
+// Разобранные поля: (символ поля, значение, позиция в тексте). Названия месяцев и дней недели хранятся как %m и %u.
+#[derive(Default)]
+struct Fields {
+    values: Vec<(char, i128, usize)>,
+    nanoseconds: Option<u32>,
+    time_zone: Option<Zone>
+}
+
+impl Fields {
+    fn get(&self, specifier: char) -> Option<i128> {
+        return self.values.iter().find(|(field, _, _)| *field == specifier).map(|(_, value, _)| *value);
+    }
+}
+
+// Самое длинное из полного названия и трёхбуквенного сокращения, без учёта регистра.
+fn parse_name(scanner: &mut Scanner, names: impl Iterator<Item = (u8, &'static str)>) -> Result<u8, Error> {
+    for (index, name) in names {
+        if scanner.eat_ignore_case(name) || scanner.eat_ignore_case(&name[..3_usize]) {
+            return Ok(index);
+        }
+    }
+
+    return Err(scanner.error());
+}
+
+fn parse_pattern(scanner: &mut Scanner, pattern: &str, fields: &mut Fields, view: CalendarView, has_date: bool) -> Result<(), Error> {
+    let mut characters = pattern.char_indices().peekable();
+
+    while let Some((_, character)) = characters.next() {
+        // Пробел в шаблоне совпадает с любым количеством пробелов в тексте, в том числе с нулём.
+        if character.is_whitespace() {
+            scanner.skip_whitespace();
+            continue;
+        } else if character != '%' {
+            if !scanner.eat_str(character.encode_utf8(&mut [0_u8; 4])) {
+                return Err(scanner.error());
+            }
+            continue;
+        }
+
+        // Флаги выравнивания и регистра при разборе не важны.
+        while characters.next_if(|&(_, flag)| "-_0^".contains(flag)).is_some() {}
+
+        let (position, specifier): (usize, char) = characters.next().ok_or(Error::InvalidFormat(pattern.len()))?;
+
+        if !has_date && DATE_SPECIFIERS.contains(specifier) {
+            return Err(Error::InvalidFormat(position));
+        }
+
+        // Год без разделителя перед следующим полем занимает четыре цифры.
+        let year_width: usize = if matches!(characters.peek(), Some((_, '%'))) { 4_usize } else { 20_usize };
+
+        if "YCyGgmdejUWVuwHkIlMS".contains(specifier) {
+            scanner.skip_whitespace();
+        }
+
+        let start: usize = scanner.position();
+
+        let (field, value): (char, i128) = match specifier {
+            'Y' | 'G' => (specifier, scanner.number_up_to(year_width)? as i128),
+            'C' | 'y' | 'g' | 'm' | 'U' | 'W' | 'V' | 'M' | 'S' => (specifier, scanner.number_up_to(2_usize)? as i128),
+            'd' | 'e' => ('d', scanner.number_up_to(2_usize)? as i128),
+            'H' | 'k' => ('H', scanner.number_up_to(2_usize)? as i128),
+            'I' | 'l' => ('I', scanner.number_up_to(2_usize)? as i128),
+            'j' => ('j', scanner.number_up_to(3_usize)? as i128),
+            'u' | 'w' => (specifier, scanner.number_up_to(1_usize)? as i128),
+            'B' | 'b' | 'h' => ('m', parse_name(scanner, (1_u8..=MONTHS_IN_YEAR).map(|month| (month, Months::from(month).name())))? as i128),
+            'A' | 'a' => ('u', parse_name(scanner, (1_u8..=DAYS_IN_WEEK).map(|week_day| (week_day, Week::from(week_day).name())))? as i128),
+            'p' | 'P' => {
+                if scanner.eat_ignore_case("AM") {
+                    ('p', 0_i128)
+                } else if scanner.eat_ignore_case("PM") {
+                    ('p', 1_i128)
+                } else {
+                    return Err(scanner.error());
+                }
+            },
+            's' => {
+                let negative: bool = scanner.eat(b'-');
+                let seconds: i128 = scanner.number_up_to(20_usize)? as i128;
+
+                ('s', if negative { -seconds } else { seconds })
+            },
+            'N' => {
+                fields.nanoseconds = Some(scanner.fraction()?);
+                continue;
+            },
+            'z' => {
+                fields.time_zone = Some(parse_zone(scanner)?.ok_or(scanner.error())?);
+                continue;
+            },
+            ':' if characters.next_if(|&(_, next)| next == 'z').is_some() => {
+                fields.time_zone = Some(parse_zone(scanner)?.ok_or(scanner.error())?);
+                continue;
+            },
+            'K' => {
+                if !scanner.eat_ignore_case(view.name()) {
+                    return Err(scanner.error());
+                }
+                continue;
+            },
+            'D' => { parse_pattern(scanner, "%m/%d/%y", fields, view, has_date)?; continue; },
+            'F' => { parse_pattern(scanner, "%Y-%m-%d", fields, view, has_date)?; continue; },
+            'T' => { parse_pattern(scanner, "%H:%M:%S", fields, view, has_date)?; continue; },
+            'R' => { parse_pattern(scanner, "%H:%M", fields, view, has_date)?; continue; },
+            'n' | 't' => { scanner.skip_whitespace(); continue; },
+            '%' => { scanner.expect(b'%')?; continue; },
+            _ => return Err(Error::InvalidFormat(position))
+        };
+
+        fields.values.push((field, value, start));
+    }
+
+    return Ok(());
+}
+
+// Полный год, либо век и две последние цифры, две цифры без века по POSIX: 69-99 - 1969-1999, 00-68 - 2000-2068.
+fn resolve_year(year: Option<i128>, century: Option<i128>, short_year: Option<i128>) -> Option<u64> {
+    return match (year, century, short_year) {
+        (Some(year), _, _) => Some(year as u64),
+        (None, Some(century), short_year) => Some((century * 100_i128 + short_year.unwrap_or(0_i128)) as u64),
+        (None, None, Some(short_year)) if short_year < 69_i128 => Some((2000_i128 + short_year) as u64),
+        (None, None, Some(short_year)) => Some((1900_i128 + short_year) as u64),
+        (None, None, None) => None
+    };
+}
+
+fn week_date(view: CalendarView, year: u64, week: i128, week_day: i128, time_zone: Zone) -> Result<Date, Error> {
+    let weeks: u8 = weeks_in_year(CalendarView::Gregorian, year)?;
+
+    if week < 1_i128 || week > weeks as i128 {
+        return Err(Error::InvalidWeek(week as u8));
+    } else if week_day < 1_i128 || week_day > DAYS_IN_WEEK as i128 {
+        return Err(Error::InvalidWeekDay(week_day as u8));
+    }
+
+    return Date::from_iso_week(CalendarView::Gregorian, year, week as u8, Week::from(week_day as u8), time_zone)?.to_view(view);
+}
+
+fn resolve_date(fields: &Fields, view: CalendarView, time_zone: Zone) -> Result<Date, Error> {
+    if let Some(year) = resolve_year(fields.get('Y'), fields.get('C'), fields.get('y')) {
+        let (month, day): (Option<i128>, Option<i128>) = (fields.get('m'), fields.get('d'));
+
+        if let (None, None, Some(year_day)) = (month, day, fields.get('j')) {
+            // Номер дня года не длиннее трёх цифр.
+            return Date::from_ordinal(view, year, year_day as u16, time_zone);
+        }
+
+        return Date::new(view, year, month.unwrap_or(1_i128) as u8, day.unwrap_or(1_i128) as u8, time_zone);
+    }
+
+    if let Some(year) = resolve_year(fields.get('G'), None, fields.get('g')) {
+        // Воскресенье %w (0) в неделе ISO последний день.
+        let week_day: i128 = match (fields.get('u'), fields.get('w')) {
+            (Some(week_day), _) => week_day,
+            (None, Some(week_day)) => if week_day == 0_i128 { DAYS_IN_WEEK as i128 } else { week_day },
+            (None, None) => return Err(Error::MissingField('u'))
+        };
+
+        return week_date(view, year, fields.get('V').ok_or(Error::MissingField('V'))?, week_day, time_zone);
+    }
+
+    return Err(Error::MissingField('Y'));
+}
+
+// Часы из %H, либо из %I с %p (без %p - до полудня).
+fn resolve_time(fields: &Fields) -> Result<(u8, u8, u8), Error> {
+    let hours: i128 = match (fields.get('H'), fields.get('I')) {
+        (Some(hours), _) => hours,
+        (None, Some(hours)) if !(1_i128..=12_i128).contains(&hours) => return Err(Error::InvalidHour(hours as u8)),
+        (None, Some(hours)) => hours % 12_i128 + fields.get('p').unwrap_or(0_i128) * 12_i128,
+        (None, None) => 0_i128
+    };
+
+    return Ok((hours as u8, fields.get('M').unwrap_or(0_i128) as u8, fields.get('S').unwrap_or(0_i128) as u8));
+}
+
+// Каждое разобранное поле должно совпасть с тем же полем готового значения, иначе - позиция противоречащего поля.
+fn check_fields(fields: &Fields, date_time: &DateTime) -> Result<(), Error> {
+    for &(specifier, value, position) in &fields.values {
+        let actual: i128 = match specifier {
+            'p' => (date_time.time.hours >= 12_u8) as i128,
+            _ => numeric_field(date_time, specifier)?
+        };
+
+        if actual != value {
+            return Err(Error::ConflictingField(position));
+        }
+    }
+
+    return Ok(());
+}
+
+impl DateTime {
+    // Значения полей проверяются календарём view, смещение %z в тексте важнее time_zone.
+    pub fn parse(text: &str, pattern: &str, view: CalendarView, time_zone: Zone) -> Result<DateTime, Error> {
+        let (mut scanner, mut fields): (Scanner, Fields) = (Scanner::new(text), Fields::default());
+
+        parse_pattern(&mut scanner, pattern, &mut fields, view, true)?;
+
+        scanner.finish()?;
+
+        let (time_zone, nanoseconds): (Zone, u32) = (fields.time_zone.unwrap_or(time_zone), fields.nanoseconds.unwrap_or(0_u32));
+
+        let date_time: DateTime = if let Some(seconds) = fields.get('s') {
+            DateTime::try_from(view, Instant::new(seconds, nanoseconds)?, time_zone)?
+        } else {
+            let (date, (hours, minutes, seconds)): (Date, (u8, u8, u8)) = (resolve_date(&fields, view, time_zone)?, resolve_time(&fields)?);
+
+            DateTime::new(view, date.year, date.month, date.day, hours, minutes, seconds, nanoseconds, time_zone)?
+        };
+
+        check_fields(&fields, &date_time)?;
+
+        return Ok(date_time);
+    }
+}
+
+impl Date {
+    pub fn parse(text: &str, pattern: &str, view: CalendarView, time_zone: Zone) -> Result<Date, Error> {
+        return Ok(DateTime::parse(text, pattern, view, time_zone)?.date);
+    }
+}
+
+impl Time {
+    // Только поля времени и смещения, unix_time отсчитывается от 1970-01-01.
+    pub fn parse(text: &str, pattern: &str, time_zone: Zone) -> Result<Time, Error> {
+        let (mut scanner, mut fields): (Scanner, Fields) = (Scanner::new(text), Fields::default());
+
+        parse_pattern(&mut scanner, pattern, &mut fields, CalendarView::default(), false)?;
+
+        scanner.finish()?;
+
+        let (time_zone, nanoseconds): (Zone, u32) = (fields.time_zone.unwrap_or(time_zone), fields.nanoseconds.unwrap_or(0_u32));
+
+        let time: Time = if let Some(seconds) = fields.get('s') {
+            Time::try_from(Instant::new(seconds, nanoseconds)?, time_zone)?
+        } else {
+            let (hours, minutes, seconds): (u8, u8, u8) = resolve_time(&fields)?;
+
+            if hours >= HOURS_IN_A_DAY {
+                return Err(Error::InvalidHour(hours));
+            } else if minutes >= MINUTES_IN_HOUR {
+                return Err(Error::InvalidMinute(minutes));
+            } else if seconds as u128 >= SECONDS_IN_MINUTE {
+                return Err(Error::InvalidSecond(seconds));
+            }
+
+            Time::from_seconds((hours as u128 * SECONDS_IN_HOUR + minutes as u128 * SECONDS_IN_MINUTE + seconds as u128) as i128, nanoseconds, time_zone)
+        };
+
+        check_fields(&fields, &DateTime { date: Date::default(), time: time })?;
+
+        return Ok(time);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Date, DateTime, Error, Time, Zone
+    };
+
+    use crate::types::{
+        data::{
+            zone::{Sign}
+        }
+    };
+
+    #[test]
+    fn test_parse_fields() {
+        let india: Zone = Zone { sign: Sign::Unsigned, hours: 5_u8, minutes: 30_u8, seconds: 0_u8 };
+
+        let expected: DateTime = DateTime::new(CalendarView::Gregorian, 2024, 3, 1, 14, 5, 0, 0, india).unwrap();
+
+        for (text, pattern) in [
+            ("01/03/2024 14:05", "%d/%m/%Y %H:%M"),
+            ("Friday, 1 MARCH 2024 2:05 pm", "%A, %e %B %Y %l:%M %p"),
+            ("fri mar  1 14:05:00 24", "%a %b %e %T %y"),
+            ("2024-061 14:05", "%Y-%j %R"),
+            ("2024-W09-5T14:05+05:30", "%G-W%V-%uT%H:%M%:z"),
+            ("20240301140500+0530", "%Y%m%d%H%M%S%z"),
+            ("1709282100", "%s"),
+            ("Gregorian 2024/03/01 14:05 Friday 09 09 08 5", "%K %Y/%m/%d %H:%M %A %V %W %U %w")
+        ] {
+            assert!(DateTime::parse(text, pattern, CalendarView::Gregorian, india).unwrap().date().same_civil_fields(&expected.date()), "{text}");
+        }
+
+        let mut date_time: DateTime = DateTime::new(CalendarView::Solar, 2023, 12, 1, 23, 59, 58, 123, india).unwrap();
+
+        for _ in 0..800 {
+            let mut text: String = String::new();
+
+            date_time.format("%a %d %b %Y %I:%M:%S.%N %p %:z %j %G-W%V-%u", &mut text).unwrap();
+
+            assert_eq!(DateTime::parse(&text, "%a %d %b %Y %I:%M:%S.%N %p %:z %j %G-W%V-%u", CalendarView::Solar, Zone::default()), Ok(date_time), "{text}");
+
+            date_time = DateTime::of(date_time.date().checked_add_days(1).unwrap());
+        }
+
+        // Поля разбираются в календаре view.
+        let julian: Date = Date::parse("16.02.2024", "%d.%m.%Y", CalendarView::Julian, india).unwrap();
+
+        assert_eq!((julian.view(), julian.to_view(CalendarView::Gregorian).unwrap().day()), (CalendarView::Julian, 29));
+        assert_eq!(Date::parse("29.02.1900", "%d.%m.%Y", CalendarView::Julian, india).map(|date| date.day()), Ok(29));
+        assert_eq!(Date::parse("1900-060", "%Y-%j", CalendarView::Julian, india).map(|date| (date.month().index(), date.day())), Ok((2, 29)));
+
+        let time: Time = Time::parse("11:30:15.5 PM -0800", "%I:%M:%S.%N %p %z", india).unwrap();
+
+        assert_eq!((time.hours, time.minutes, time.seconds, time.nanoseconds, time.unix_time), (23, 30, 15, 500_000_000, 113_415));
+        assert_eq!(Time::parse("12 am", "%I %P", india).map(|time| time.hours), Ok(0));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let zone: Zone = Zone::default();
+
+        for (text, pattern, error) in [
+            ("29/02/2023", "%d/%m/%Y", Error::InvalidDay(29)),
+            ("01/13/2024", "%d/%m/%Y", Error::InvalidMonth(13)),
+            ("2023-366", "%Y-%j", Error::InvalidYearDay(366)),
+            ("2021-W53-1", "%G-W%V-%u", Error::InvalidWeek(53)),
+            ("0000-W01-1", "%G-W%V-%u", Error::InvalidYear(0)),
+            ("25:00 2024", "%H:%M %Y", Error::InvalidHour(25)),
+            ("13 PM 2024", "%I %p %Y", Error::InvalidHour(13)),
+            ("Thursday 2024-03-01", "%A %F", Error::ConflictingField(0)),
+            ("2024-03-01 062", "%F %j", Error::ConflictingField(11)),
+            ("2024-03-01 15 AM", "%F %H %p", Error::ConflictingField(14)),
+            ("2024 99", "%Y %y", Error::ConflictingField(5)),
+            ("Smarch 2024", "%B %Y", Error::InvalidFormat(0)),
+            ("2024/03-01", "%Y-%m-%d", Error::InvalidFormat(4)),
+            ("2024-03-01 ", "%F", Error::InvalidFormat(10)),
+            ("2024-03", "%F", Error::InvalidFormat(7)),
+            ("2024", "%Y %J", Error::InvalidFormat(4)),
+            ("03-01", "%m-%d", Error::MissingField('Y')),
+            ("2024-W09", "%G-W%V", Error::MissingField('u'))
+        ] {
+            assert_eq!(DateTime::parse(text, pattern, CalendarView::Gregorian, zone), Err(error), "{text}");
+        }
+
+        assert_eq!(Time::parse("01 12:00", "%d %H:%M", zone), Err(Error::InvalidFormat(1)));
+        assert_eq!(Time::parse("12:60", "%H:%M", zone), Err(Error::InvalidMinute(60)));
+    }
+}
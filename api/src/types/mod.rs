@@ -27,5 +27,7 @@
  */
 
 pub(crate) mod data;
+pub(crate) mod error;
 pub(crate) mod counter;
-pub(crate) mod planets;
\ No newline at end of file
+pub(crate) mod planets;
+pub(crate) mod format;
\ No newline at end of file
@@ -30,6 +30,10 @@ use super::{
     seconds::{BASE_MONTH_SECONDS, CALENDAR_LEAP_SECONDS_IN_LEAP_YEAR}
 };
 
+use crate::types::{
+    error::{Error}
+};
+
 pub const MONTHS_IN_YEAR: u8 = 12_u8;
 
 pub const BASE_MONTH_DAYS: [u8; MONTHS_IN_YEAR as usize] = [31_u8, 28_u8, 31_u8, 30_u8, 31_u8, 30_u8, 31_u8, 31_u8, 30_u8, 31_u8, 30_u8, 31_u8];
@@ -132,20 +136,27 @@ impl Months {
     }
 
     pub fn from(month: u8) -> Months {
+        match Months::try_from(month) {
+            Ok(month) => return month,
+            Err(error) => panic!("{}", error)
+        }
+    }
+
+    pub const fn try_from(month: u8) -> Result<Months, Error> {
         match month {
-            1_u8         => Months::January,
-            2_u8         => Months::February,
-            3_u8         => Months::March,
-            4_u8         => Months::April,
-            5_u8         => Months::May,
-            6_u8         => Months::June,
-            7_u8         => Months::July,
-            8_u8         => Months::August,
-            9_u8         => Months::September,
-            10_u8        => Months::October,
-            11_u8        => Months::November,
-            12_u8 | 0_u8 => Months::December,
-            _  => panic!("Invalid month: {}", month),
+            1_u8         => Ok(Months::January),
+            2_u8         => Ok(Months::February),
+            3_u8         => Ok(Months::March),
+            4_u8         => Ok(Months::April),
+            5_u8         => Ok(Months::May),
+            6_u8         => Ok(Months::June),
+            7_u8         => Ok(Months::July),
+            8_u8         => Ok(Months::August),
+            9_u8         => Ok(Months::September),
+            10_u8        => Ok(Months::October),
+            11_u8        => Ok(Months::November),
+            12_u8 | 0_u8 => Ok(Months::December),
+            _  => Err(Error::InvalidMonth(month)),
         }
     }
 
@@ -162,6 +173,6 @@ impl Months {
     }
 
     pub fn previous_nth(&self, nth: u128) -> Months {
-        return Months::from(((self.index() as u128 - nth) % MONTHS_IN_YEAR as u128) as u8);
+        return Months::from(((self.index() as u128 + MONTHS_IN_YEAR as u128 - (nth % MONTHS_IN_YEAR as u128)) % MONTHS_IN_YEAR as u128) as u8);
     }
 }
\ No newline at end of file
@@ -34,6 +34,8 @@ use super::{
     months::{BASE_MONTH_DAYS, LEAP_MONTH_DAYS, MONTHS_IN_YEAR}
 };
 
+pub const NANOSECONDS_IN_SECOND: u32 = 1_000_000_000_u32;
+
 pub const SECONDS_IN_MINUTE: u128 = 60_u128;
 pub const SECONDS_IN_HOUR: u128 = SECONDS_IN_MINUTE * (MINUTES_IN_HOUR as u128);
 pub const SECONDS_IN_DAY: u128 = SECONDS_IN_HOUR * (HOURS_IN_A_DAY as u128);
@@ -26,6 +26,10 @@
  * THE SOFTWARE.
  */
 
+use crate::types::{
+    error::{Error}
+};
+
 pub const DAYS_IN_WEEK: u8 = 7_u8;
 pub const REPEAT_WEAK_DAY_CYCLE: u8 = DAYS_IN_WEEK;
 
@@ -33,7 +37,7 @@ pub const SHIFT_BEFORE_FIRST_PRESENTATION_WEEK_DAY_SOLAR: u8 = 0_u8;
 pub const SHIFT_BEFORE_FIRST_PRESENTATION_WEEK_DAY_JULIAN: u8 = 5_u8;
 pub const SHIFT_BEFORE_FIRST_PRESENTATION_WEEK_DAY_GREGORIAN: u8 = 0_u8;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Week {
     Monday    = 1,
     Tuesday   = 2,
@@ -70,15 +74,22 @@ impl Week {
     }
 
     pub fn from(week_day: u8) -> Week {
+        match Week::try_from(week_day) {
+            Ok(week) => return week,
+            Err(error) => panic!("{}", error)
+        }
+    }
+
+    pub const fn try_from(week_day: u8) -> Result<Week, Error> {
         match week_day {
-            1_u8         => Week::Monday,
-            2_u8         => Week::Tuesday,
-            3_u8         => Week::Wednesday,
-            4_u8         => Week::Thursday,
-            5_u8         => Week::Friday,
-            6_u8         => Week::Saturday,
-            7_u8 | 0_u8  => Week::Sunday,
-            _  => panic!("Invalid week day: {}", week_day),
+            1_u8         => Ok(Week::Monday),
+            2_u8         => Ok(Week::Tuesday),
+            3_u8         => Ok(Week::Wednesday),
+            4_u8         => Ok(Week::Thursday),
+            5_u8         => Ok(Week::Friday),
+            6_u8         => Ok(Week::Saturday),
+            7_u8 | 0_u8  => Ok(Week::Sunday),
+            _  => Err(Error::InvalidWeekDay(week_day)),
         }
     }
 
@@ -95,6 +106,6 @@ impl Week {
     }
 
     pub fn previous_nth(&self, nth: u128) -> Week {
-        return Week::from(((self.index() as u128 - nth) % DAYS_IN_WEEK as u128) as u8);
+        return Week::from(((self.index() as u128 + DAYS_IN_WEEK as u128 - (nth % DAYS_IN_WEEK as u128)) % DAYS_IN_WEEK as u128) as u8);
     }
 }
\ No newline at end of file
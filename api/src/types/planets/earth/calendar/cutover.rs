@@ -0,0 +1,247 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use crate::types::{
+    error::{Error},
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                constants::{
+                    days::{JULIAN_BCE_DAYS_FIRST_YEAR}
+                },
+                functions::{
+                    days_from_presentation_date, validate_presentation_date
+                },
+            }
+        }
+    },
+    counter::{
+        unix_time::{
+            functions::{
+                year_from_presentation_days, month_from_days
+            }
+        }
+    }
+};
+
+// Переход с юлианского календаря на григорианский: первый день по григорианскому календарю.
+// Дни между последним юлианским и первым григорианским днём пропущены, дни эры при этом идут без разрыва.
+// Создаётся только через new() или константы, поэтому Default нет: нулевая дата перехода не проходит проверки.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cutover {
+    pub(crate) year: u64,
+    pub(crate) month: u8,
+    pub(crate) day: u8
+}
+
+impl Cutover {
+    // Италия, Испания, Португалия, Речь Посполитая.
+    pub const ROME: Cutover = Cutover { year: 1582, month: 10, day: 15 };
+    pub const FRANCE: Cutover = Cutover { year: 1582, month: 12, day: 20 };
+    // Дания, Норвегия и протестантские земли Германии.
+    pub const DENMARK: Cutover = Cutover { year: 1700, month: 3, day: 1 };
+    // Великобритания и колонии.
+    pub const GREAT_BRITAIN: Cutover = Cutover { year: 1752, month: 9, day: 14 };
+    pub const SWEDEN: Cutover = Cutover { year: 1753, month: 3, day: 1 };
+    pub const BULGARIA: Cutover = Cutover { year: 1916, month: 4, day: 14 };
+    pub const RUSSIA: Cutover = Cutover { year: 1918, month: 2, day: 14 };
+    pub const YUGOSLAVIA: Cutover = Cutover { year: 1919, month: 1, day: 28 };
+    pub const ROMANIA: Cutover = Cutover { year: 1919, month: 4, day: 14 };
+    pub const GREECE: Cutover = Cutover { year: 1923, month: 3, day: 1 };
+
+    pub const COMMON: [(&'static str, Cutover); 10] = [
+        ("Rome", Cutover::ROME),
+        ("France", Cutover::FRANCE),
+        ("Denmark", Cutover::DENMARK),
+        ("Great Britain", Cutover::GREAT_BRITAIN),
+        ("Sweden", Cutover::SWEDEN),
+        ("Bulgaria", Cutover::BULGARIA),
+        ("Russia", Cutover::RUSSIA),
+        ("Yugoslavia", Cutover::YUGOSLAVIA),
+        ("Romania", Cutover::ROMANIA),
+        ("Greece", Cutover::GREECE)
+    ];
+
+    // Григорианский календарь должен опережать юлианский хотя бы на день: иначе переход не пропускает дни.
+    pub fn new(year: u64, month: u8, day: u8) -> Result<Cutover, Error> {
+        validate_presentation_date(CalendarView::Gregorian, year, month, day)?;
+
+        let cutover: Cutover = Cutover { year: year, month: month, day: day };
+
+        let (last_year, mut last_days): (u64, u16) = year_from_presentation_days(CalendarView::Julian, cutover.era_days() - 1_u128 + JULIAN_BCE_DAYS_FIRST_YEAR);
+        let last_ordinal: u16 = last_days;
+        let last_month: u8 = month_from_days(CalendarView::Julian, last_year, &mut last_days).index();
+
+        if (last_year, last_month, last_days as u8) >= (year, month, day) || (last_year == year && last_ordinal >= cutover.ordinal()) {
+            return Err(Error::InvalidDay(day));
+        }
+
+        return Ok(cutover);
+    }
+
+    pub fn from_name(name: &str) -> Option<Cutover> {
+        return Cutover::COMMON.iter().find(|(country, _)| country.eq_ignore_ascii_case(name)).map(|(_, cutover)| *cutover);
+    }
+
+    pub const fn year(&self) -> u64 {
+        return self.year;
+    }
+
+    pub const fn month(&self) -> u8 {
+        return self.month;
+    }
+
+    pub const fn day(&self) -> u8 {
+        return self.day;
+    }
+
+    // День эры первого григорианского дня.
+    pub fn era_days(&self) -> u128 {
+        return days_from_presentation_date(CalendarView::Gregorian, self.year, self.month, self.day);
+    }
+
+    // Календарь, по правилам которого записана дата: до перехода юлианский, с первого дня перехода григорианский.
+    pub(crate) fn view_of(&self, year: u64, month: u8, day: u8) -> CalendarView {
+        if (year, month, day) < (self.year, self.month, self.day) {
+            return CalendarView::Julian;
+        } else {
+            return CalendarView::Gregorian;
+        }
+    }
+
+    // Порядковый номер первого григорианского дня в году перехода.
+    pub(crate) fn ordinal(&self) -> u16 {
+        return (self.era_days() - days_from_presentation_date(CalendarView::Gregorian, self.year, 0_u8, 0_u8)) as u16;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Cutover, Error
+    };
+
+    use crate::{
+        Date, DateRange, Step, Sign, Zone, MonthEnd,
+        RataDie, Sakamoto, Xavetar, Week,
+        functions::{
+            is_leap_year, sum_leap_years
+        },
+    };
+
+    fn week_days(date: &Date) -> [u8; 3] {
+        return [<Date as RataDie>::week_day(date).index(), <Date as Sakamoto>::week_day(date).index(), <Date as Xavetar>::week_day(date).index()];
+    }
+
+    #[test]
+    fn test_cutover_table() {
+        for (name, cutover) in Cutover::COMMON {
+            assert_eq!(Cutover::new(cutover.year(), cutover.month(), cutover.day()), Ok(cutover));
+            assert_eq!(Cutover::from_name(&name.to_uppercase()), Some(cutover));
+        }
+
+        assert_eq!(Cutover::from_name("Atlantis"), None);
+        assert_eq!(Cutover::new(1582, 10, 32), Err(Error::InvalidDay(32)));
+        assert_eq!(Cutover::new(100, 1, 1), Err(Error::InvalidDay(1)));
+    }
+
+    #[test]
+    fn test_historical_gap() {
+        let time_zone: Zone = Zone { sign: Sign::Unsigned, hours: 1_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        for (cutover, last, first, skipped, week_day) in [
+            (Cutover::ROME, (1582, 10, 4), (1582, 10, 15), (1582, 10, 10), Week::Thursday),
+            (Cutover::GREAT_BRITAIN, (1752, 9, 2), (1752, 9, 14), (1752, 9, 3), Week::Wednesday),
+            (Cutover::DENMARK, (1700, 2, 18), (1700, 3, 1), (1700, 2, 19), Week::Sunday),
+            (Cutover::RUSSIA, (1918, 1, 31), (1918, 2, 14), (1918, 2, 13), Week::Wednesday)
+        ] {
+            let view: CalendarView = CalendarView::Historical(cutover);
+
+            let julian: Date = Date::new(view, last.0, last.1, last.2, time_zone).unwrap();
+            let gregorian: Date = Date::new(view, first.0, first.1, first.2, time_zone).unwrap();
+
+            assert_eq!(julian, Date::new(CalendarView::Julian, last.0, last.1, last.2, time_zone).unwrap());
+            assert_eq!(gregorian, Date::new(CalendarView::Gregorian, first.0, first.1, first.2, time_zone).unwrap());
+            assert_eq!(gregorian.era_days(), julian.era_days() + 1);
+            assert_eq!(gregorian.era_days(), cutover.era_days());
+
+            assert!(julian.checked_add_days(1).unwrap().same_civil_fields(&gregorian));
+            assert!(gregorian.checked_sub_days(1).unwrap().same_civil_fields(&julian));
+            assert!(julian.to_view(CalendarView::Gregorian).unwrap().to_view(view).unwrap().same_civil_fields(&julian));
+
+            assert_eq!(week_days(&julian), [week_day.index(); 3]);
+            assert_eq!(week_days(&gregorian), [week_day.next().index(); 3]);
+
+            assert_eq!(Date::new(view, skipped.0, skipped.1, skipped.2, time_zone), Err(Error::SkippedDay(skipped.2)));
+        }
+
+        let view: CalendarView = CalendarView::Historical(Cutover::DENMARK);
+
+        assert!(is_leap_year(view, 1696) && is_leap_year(CalendarView::Historical(Cutover::RUSSIA), 1900) && !is_leap_year(CalendarView::Historical(Cutover::ROME), 1900));
+        assert_eq!(Date::new(view, 1700, 2, 29, time_zone), Err(Error::SkippedDay(29)));
+        assert_eq!(Date::new(view, 1700, 2, 30, time_zone), Err(Error::InvalidDay(30)));
+
+        let russia: Date = Date::new(CalendarView::Historical(Cutover::RUSSIA), 1918, 1, 5, time_zone).unwrap();
+        assert_eq!(russia.add_months(1, MonthEnd::Clamp), Err(Error::SkippedDay(5)));
+        assert_eq!(russia.add_months(2, MonthEnd::Clamp).map(|date| (date.month().index(), date.day())), Ok((3, 5)));
+    }
+
+    #[test]
+    fn test_historical_cutover_year() {
+        let time_zone: Zone = Zone { sign: Sign::Signed, hours: 4_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        for (name, cutover) in Cutover::COMMON {
+            let view: CalendarView = CalendarView::Historical(cutover);
+
+            let (start, end): (Date, Date)
+            =
+            (
+                Date::new(view, cutover.year() - 1, 12, 31, time_zone).unwrap(),
+                Date::new(view, cutover.year() + 1, 1, 1, time_zone).unwrap()
+            );
+
+            let days: Vec<Date> = DateRange::exclusive(start, end, Step::Days(1)).unwrap().collect();
+
+            assert_eq!(days.len() as u128, end.era_days() - start.era_days(), "{name}");
+            assert!(days.len() < 367 && days.len() > 350, "{name}");
+
+            for pair in days.windows(2) {
+                assert_eq!(pair[1].era_days(), pair[0].era_days() + 1, "{name}");
+                assert!((pair[0].year(), pair[0].month().index(), pair[0].day()) < (pair[1].year(), pair[1].month().index(), pair[1].day()), "{name}");
+            }
+
+            for date in days {
+                assert!(Date::new(view, date.year(), date.month().index(), date.day(), time_zone).unwrap().same_civil_fields(&date), "{name}");
+                assert!(date.to_view(CalendarView::Solar).unwrap().to_view(view).unwrap().same_civil_fields(&date), "{name}");
+            }
+
+            assert_eq!(sum_leap_years(view, 2100), (1_u64..=2100_u64).filter(|year| is_leap_year(view, *year)).count() as u64, "{name}");
+        }
+    }
+}
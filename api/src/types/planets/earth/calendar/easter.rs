@@ -0,0 +1,126 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use crate::types::{
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView}
+            }
+        }
+    }
+};
+
+// Пасхалия: западная по григорианскому календарю, православная по юлианскому.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Computus {
+    #[default]
+    Western,
+    Orthodox
+}
+
+impl Computus {
+    // Календарь, в котором пасхалия даёт месяц и день.
+    pub const fn view(&self) -> CalendarView {
+        return match self {
+            Computus::Western => CalendarView::Gregorian,
+            Computus::Orthodox => CalendarView::Julian
+        };
+    }
+
+    // Месяц и день Пасхи года year в календаре view().
+    pub const fn month_day(&self, year: u64) -> (u8, u8) {
+        let golden_number: u64 = year % 19_u64;
+
+        let days: u64 = match self {
+            Computus::Western => {
+                // Анонимный григорианский алгоритм (Meeus/Jones/Butcher).
+                let (century, year_of_century): (u64, u64) = (year / 100_u64, year % 100_u64);
+
+                let (leap_centuries, correction): (u64, u64) = (century / 4_u64, (century - (century + 8_u64) / 25_u64 + 1_u64) / 3_u64);
+
+                let epact: u64 = (19_u64 * golden_number + century - leap_centuries - correction + 15_u64) % 30_u64;
+
+                let week_day: u64 = (32_u64 + 2_u64 * (century % 4_u64) + 2_u64 * (year_of_century / 4_u64) - epact - year_of_century % 4_u64) % 7_u64;
+
+                epact + week_day - 7_u64 * ((golden_number + 11_u64 * epact + 22_u64 * week_day) / 451_u64) + 114_u64
+            },
+            Computus::Orthodox => {
+                let epact: u64 = (19_u64 * golden_number + 15_u64) % 30_u64;
+
+                epact + (2_u64 * (year % 4_u64) + 4_u64 * (year % 7_u64) + 34_u64 - epact) % 7_u64 + 114_u64
+            }
+        };
+
+        return ((days / 31_u64) as u8, (days % 31_u64) as u8 + 1_u8);
+    }
+}
+
+// Переходящие праздники, отсчитываемые от Пасхи.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Feast {
+    AshWednesday,
+    PalmSunday,
+    GoodFriday,
+    Easter,
+    EasterMonday,
+    Ascension,
+    Pentecost,
+    WhitMonday,
+    CorpusChristi
+}
+
+impl Feast {
+    pub const fn days_from_easter(&self) -> i16 {
+        return match self {
+            Feast::AshWednesday  => -46_i16,
+            Feast::PalmSunday    => -7_i16,
+            Feast::GoodFriday    => -2_i16,
+            Feast::Easter        => 0_i16,
+            Feast::EasterMonday  => 1_i16,
+            Feast::Ascension     => 39_i16,
+            Feast::Pentecost     => 49_i16,
+            Feast::WhitMonday    => 50_i16,
+            Feast::CorpusChristi => 60_i16
+        };
+    }
+
+    pub const fn name(&self) -> &'static str {
+        return match self {
+            Feast::AshWednesday  => "Ash Wednesday",
+            Feast::PalmSunday    => "Palm Sunday",
+            Feast::GoodFriday    => "Good Friday",
+            Feast::Easter        => "Easter",
+            Feast::EasterMonday  => "Easter Monday",
+            Feast::Ascension     => "Ascension",
+            Feast::Pentecost     => "Pentecost",
+            Feast::WhitMonday    => "Whit Monday",
+            Feast::CorpusChristi => "Corpus Christi"
+        };
+    }
+}
@@ -31,16 +31,25 @@ use super::{
 };
 
 use crate::types::{
+    error::{Error},
     planets::earth::calendar::{
         view::{CalendarView},
         constants::{
-            days::{BASE_MONTH_SUM_DAYS, LEAP_MONTH_SUM_DAYS},
+            days::{BASE_MONTH_SUM_DAYS, LEAP_MONTH_SUM_DAYS, JULIAN_BCE_DAYS_FIRST_YEAR},
             year::{BASE_DAYS_YEAR, LEAP_DAYS_YEAR}
         },
     }
 };
 
 pub fn days_from_presentation_date(view: CalendarView, year: u64, month: u8, day: u8) -> u128 {
+    // Дни представления исторического календаря юлианские: после перехода к григорианским дням эры добавляется сдвиг юлианского.
+    if let CalendarView::Historical(cutover) = view {
+        if cutover.view_of(year, month, day) == CalendarView::Gregorian {
+            return days_from_presentation_date(CalendarView::Gregorian, year, month, day) + JULIAN_BCE_DAYS_FIRST_YEAR;
+        } else {
+            return days_from_presentation_date(CalendarView::Julian, year, month, day);
+        }
+    }
 
     let (mut days, leap_year, leap_years): (u128, bool, u64)
     =
@@ -54,6 +63,16 @@ pub fn days_from_presentation_date(view: CalendarView, year: u64, month: u8, day
         (only_base_years, only_leap_years) = (year - leap_years, leap_years - 1_u64);
     }
 
+    if let CalendarView::Custom(system) = view {
+        days += only_base_years as u128 * system.year_days(false) as u128 + only_leap_years as u128 * system.year_days(true) as u128;
+
+        for previous_month in 1_u8..month {
+            days += system.month_days(previous_month, leap_year) as u128;
+        }
+
+        return days;
+    }
+
     days += only_base_years as u128 * BASE_DAYS_YEAR as u128 + only_leap_years as u128 * LEAP_DAYS_YEAR as u128;
 
     if month > 1_u8 {
@@ -67,13 +86,31 @@ pub fn days_from_presentation_date(view: CalendarView, year: u64, month: u8, day
     return days;
 }
 
+pub fn days_in_month(view: CalendarView, year: u64, month: u8) -> Result<u8, Error> {
+    if year == 0_u64 {
+        return Err(Error::InvalidYear(year));
+    } else if !(1_u8..=view.system().months_in_year()).contains(&month) {
+        return Err(Error::InvalidMonth(month));
+    }
+
+    return Ok(view.system().month_days(month, is_leap_year(view, year)));
+}
+
+// Последний день представления последнего года (u64::MAX), дальше дни представления не помещаются.
+pub fn last_presentation_day(view: CalendarView) -> u128 {
+    let last_month: u8 = view.system().months_in_year();
+
+    return days_from_presentation_date(view, u64::MAX, last_month, view.system().month_days(last_month, is_leap_year(view, u64::MAX)));
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
         CalendarView,
         BASE_DAYS_YEAR, LEAP_DAYS_YEAR,
         BASE_MONTH_SUM_DAYS, LEAP_MONTH_SUM_DAYS,
-        days_from_presentation_date, is_leap_year, sum_leap_years
+        Error,
+        days_from_presentation_date, days_in_month, is_leap_year, sum_leap_years
     };
 
     #[test]
@@ -171,4 +208,18 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+    #[test]
+    fn test_days_in_month() {
+        for (view, year, month, days) in [
+            (CalendarView::Gregorian, 2024, 2, Ok(29)),
+            (CalendarView::Gregorian, 1900, 2, Ok(28)),
+            (CalendarView::Julian, 1900, 2, Ok(29)),
+            (CalendarView::Solar, 2024, 12, Ok(31)),
+            (CalendarView::Gregorian, 2024, 13, Err(Error::InvalidMonth(13))),
+            (CalendarView::Gregorian, 0, 2, Err(Error::InvalidYear(0))),
+            (CalendarView::Solar, 0, 2, Err(Error::InvalidYear(0)))
+        ] {
+            assert_eq!(days_in_month(view, year, month), days, "{view:?} {year}-{month}");
+        }
+    }
+}
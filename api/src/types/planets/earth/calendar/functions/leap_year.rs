@@ -41,7 +41,7 @@ use crate::types::{
     }
 };
 
-pub const fn is_leap_year(view: CalendarView, year: u64) -> bool {
+pub fn is_leap_year(view: CalendarView, year: u64) -> bool {
     match view {
         CalendarView::Julian => return year % 4_u64 == 0_u64,
         CalendarView::Gregorian => return year % 4_u64 == 0_u64 && (year % 100_u64 != 0_u64 || year % 400_u64 == 0_u64),
@@ -50,10 +50,13 @@ pub const fn is_leap_year(view: CalendarView, year: u64) -> bool {
         // Equivalent to (truncating division, but float point):
         //      let leap_years_fraction: f64 = ((((year - 1_u64) % 100000_u64) * SOLAR_YEAR_LEAP_LENGTH_INT) % 100000_u64) as f64 / 100000.0_f64;
         CalendarView::Solar => return sum_leap_years(view, year) > sum_leap_years(view, year - 1),
+        CalendarView::Custom(system) => return system.is_leap_year(year),
+        // Февраль года перехода считается по календарю, который действует на 29 февраля.
+        CalendarView::Historical(cutover) => return is_leap_year(cutover.view_of(year, 2_u8, 29_u8), year),
     }
 }
 
-pub const fn sum_leap_years(view: CalendarView, year: u64) -> u64 {
+pub fn sum_leap_years(view: CalendarView, year: u64) -> u64 {
     match view {
         CalendarView::Julian => return year / 4_u64,
         CalendarView::Gregorian => return year / 4_u64 - year / 100_u64 + year / 400_u64,
@@ -65,5 +68,15 @@ pub const fn sum_leap_years(view: CalendarView, year: u64) -> u64 {
         // Equivalent to (truncating division):
         //      return (year.div_euclid(100000_u64) * SOLAR_YEAR_LEAP_LENGTH_INT) + (((year % 100000_u64) * SOLAR_YEAR_LEAP_LENGTH_INT) / 100000_u64)
         CalendarView::Solar => return (((year - (year % 100000_u64)) / 100000_u64) * SOLAR_YEAR_LEAP_LENGTH_INT) + (((year % 100000_u64) * SOLAR_YEAR_LEAP_LENGTH_INT) / 100000_u64),
+        CalendarView::Custom(system) => return system.sum_leap_years(year),
+        CalendarView::Historical(cutover) => {
+            if year < cutover.year {
+                return sum_leap_years(CalendarView::Julian, year);
+            } else {
+                return sum_leap_years(CalendarView::Julian, cutover.year - 1_u64)
+                    + is_leap_year(view, cutover.year) as u64
+                    + sum_leap_years(CalendarView::Gregorian, year) - sum_leap_years(CalendarView::Gregorian, cutover.year);
+            }
+        },
     }
 }
\ No newline at end of file
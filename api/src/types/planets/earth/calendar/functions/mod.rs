@@ -28,6 +28,11 @@
 
 mod days;
 mod leap_year;
+mod validate;
+mod week;
 
-pub use days::{days_from_presentation_date};
-pub use leap_year::{is_leap_year, sum_leap_years};
\ No newline at end of file
+pub use days::{days_from_presentation_date, days_in_month, last_presentation_day};
+pub use leap_year::{is_leap_year, sum_leap_years};
+pub use validate::{validate_presentation_date};
+pub use week::{weeks_in_year, us_weeks_in_year};
+pub(crate) use week::{WeekRule, ISO_WEEK, US_WEEK, year_days};
\ No newline at end of file
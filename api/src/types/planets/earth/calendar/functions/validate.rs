@@ -0,0 +1,93 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use super::{
+    days_from_presentation_date, days_in_month
+};
+
+use crate::types::{
+    error::{Error},
+    planets::earth::calendar::{
+        view::{CalendarView},
+        constants::{
+            days::{JULIAN_BCE_DAYS_FIRST_YEAR}
+        },
+    }
+};
+
+pub fn validate_presentation_date(view: CalendarView, year: u64, month: u8, day: u8) -> Result<(), Error> {
+    if year == 0_u64 {
+        return Err(Error::InvalidYear(year));
+    }
+
+    if day == 0_u8 || day > days_in_month(view, year, month)? {
+        return Err(Error::InvalidDay(day));
+    }
+
+    // Первые дни представления первого года могут находиться до начала эры (у юлианского календаря два).
+    if year == 1_u64 && days_from_presentation_date(view, year, month, day) <= view.system().epoch_offset() {
+        return Err(Error::OutsideEra(view));
+    }
+
+    // Юлианские даты, которые приходятся на первый григорианский день или позже, пропущены реформой.
+    if let CalendarView::Historical(cutover) = view {
+        if cutover.view_of(year, month, day) == CalendarView::Julian
+            && days_from_presentation_date(CalendarView::Julian, year, month, day) >= cutover.era_days() + JULIAN_BCE_DAYS_FIRST_YEAR {
+            return Err(Error::SkippedDay(day));
+        }
+    }
+
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarView, Error,
+        validate_presentation_date
+    };
+
+    #[test]
+    fn test_validate_presentation_date() {
+        for view in [CalendarView::Solar, CalendarView::Julian, CalendarView::Gregorian] {
+            assert_eq!(validate_presentation_date(view, 0, 1, 10), Err(Error::InvalidYear(0)));
+            assert_eq!(validate_presentation_date(view, 2024, 0, 10), Err(Error::InvalidMonth(0)));
+            assert_eq!(validate_presentation_date(view, 2024, 13, 10), Err(Error::InvalidMonth(13)));
+            assert_eq!(validate_presentation_date(view, 2024, 4, 0), Err(Error::InvalidDay(0)));
+            assert_eq!(validate_presentation_date(view, 2024, 4, 31), Err(Error::InvalidDay(31)));
+            assert_eq!(validate_presentation_date(view, 2023, 2, 29), Err(Error::InvalidDay(29)));
+            assert_eq!(validate_presentation_date(view, 2024, 12, 31), Ok(()));
+        }
+
+        assert_eq!(validate_presentation_date(CalendarView::Julian, 1, 1, 2), Err(Error::OutsideEra(CalendarView::Julian)));
+        assert_eq!(validate_presentation_date(CalendarView::Julian, 1, 1, 3), Ok(()));
+        assert_eq!(validate_presentation_date(CalendarView::Gregorian, 1, 1, 1), Ok(()));
+        assert_eq!(validate_presentation_date(CalendarView::Julian, 1900, 2, 29), Ok(()));
+        assert_eq!(validate_presentation_date(CalendarView::Gregorian, 1900, 2, 29), Err(Error::InvalidDay(29)));
+    }
+}
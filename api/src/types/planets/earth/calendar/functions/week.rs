@@ -0,0 +1,115 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use super::{
+    is_leap_year, days_from_presentation_date
+};
+
+use crate::types::{
+    error::{Error},
+    data::{
+        date::{Date}
+    },
+    planets::earth::calendar::{
+        view::{CalendarView},
+        constants::{
+            week::{Week, DAYS_IN_WEEK}
+        },
+        traits::{
+            day_of_week::{RataDie}
+        }
+    }
+};
+
+// Нумерация недель: первый день недели (0 - воскресенье, 1 - понедельник) и наименьшее число дней года в первой неделе.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct WeekRule {
+    pub(crate) first: u8,
+    pub(crate) min_days: u8
+}
+
+// ISO 8601: недели с понедельника, первая неделя та, в которую попадает 4 января.
+pub(crate) const ISO_WEEK: WeekRule = WeekRule { first: 1_u8, min_days: 4_u8 };
+// США: недели с воскресенья, первая неделя та, в которую попадает 1 января.
+pub(crate) const US_WEEK: WeekRule = WeekRule { first: 0_u8, min_days: 1_u8 };
+
+impl WeekRule {
+    // Номер дня в неделе правила, начиная с нуля.
+    pub(crate) const fn position(&self, week_day: &Week) -> u8 {
+        return (week_day.index() % DAYS_IN_WEEK + DAYS_IN_WEEK - self.first) % DAYS_IN_WEEK;
+    }
+
+    // Сдвиг начала первой недели от 1 января, день недели которого week_day.
+    const fn offset(&self, week_day: &Week) -> i128 {
+        let position: u8 = self.position(week_day);
+
+        if DAYS_IN_WEEK - position >= self.min_days {
+            return -(position as i128);
+        }
+
+        return (DAYS_IN_WEEK - position) as i128;
+    }
+
+    pub(crate) fn first_week_offset(&self, view: CalendarView, year: u64) -> i128 {
+        return self.offset(&<Date as RataDie>::from(view, year, 1_u8, 1_u8));
+    }
+
+    pub(crate) fn weeks_in_year(&self, view: CalendarView, year: u64) -> u8 {
+        let (first_day, days): (Week, u128) = (<Date as RataDie>::from(view, year, 1_u8, 1_u8), year_days(view, year));
+
+        // 1 января следующего года определяется длиной года, без перехода за последний год.
+        let next_first_day: Week = first_day.next_nth(days);
+
+        return ((days as i128 + self.offset(&next_first_day) - self.offset(&first_day)) / DAYS_IN_WEEK as i128) as u8;
+    }
+}
+
+// Длина года в днях, у года перехода исторического календаря без пропущенных дней.
+pub(crate) fn year_days(view: CalendarView, year: u64) -> u128 {
+    let last_month: u8 = view.system().months_in_year();
+
+    let last_day: u128 = days_from_presentation_date(view, year, last_month, view.system().month_days(last_month, is_leap_year(view, year)));
+
+    return last_day - days_from_presentation_date(view, year, 1_u8, 1_u8) + 1_u128;
+}
+
+pub fn weeks_in_year(view: CalendarView, year: u64) -> Result<u8, Error> {
+    if year == 0_u64 {
+        return Err(Error::InvalidYear(year));
+    }
+
+    return Ok(ISO_WEEK.weeks_in_year(view, year));
+}
+
+pub fn us_weeks_in_year(view: CalendarView, year: u64) -> Result<u8, Error> {
+    if year == 0_u64 {
+        return Err(Error::InvalidYear(year));
+    }
+
+    return Ok(US_WEEK.weeks_in_year(view, year));
+}
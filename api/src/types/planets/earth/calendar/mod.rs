@@ -29,4 +29,7 @@
 pub(crate) mod constants;
 pub(crate) mod functions;
 pub(crate) mod traits;
-pub(crate) mod view;
\ No newline at end of file
+pub(crate) mod view;
+pub(crate) mod system;
+pub(crate) mod cutover;
+pub(crate) mod easter;
\ No newline at end of file
@@ -0,0 +1,278 @@
+/*
+ * Copyright 2024 Stanislav Mikhailov (xavetar)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+use std::{
+    fmt::{Debug}
+};
+
+use crate::types::{
+    planets::{
+        earth::{
+            calendar::{
+                view::{CalendarView},
+                constants::{
+                    days::{JULIAN_BCE_DAYS_FIRST_YEAR},
+                    year::{BASE_DAYS_YEAR, LEAP_DAYS_YEAR},
+                    months::{Months, MONTHS_IN_YEAR},
+                    week::{
+                        SHIFT_BEFORE_FIRST_PRESENTATION_WEEK_DAY_SOLAR,
+                        SHIFT_BEFORE_FIRST_PRESENTATION_WEEK_DAY_JULIAN,
+                        SHIFT_BEFORE_FIRST_PRESENTATION_WEEK_DAY_GREGORIAN,
+                    },
+                },
+                functions::{
+                    is_leap_year, sum_leap_years
+                },
+            }
+        }
+    }
+};
+
+// Календарная система: правило високосных лет, месяцы года и сдвиги дней представления относительно дней эры.
+// Месяцев в году не больше MONTHS_IN_YEAR и дней в месяце не больше 31, названия месяцев берутся из Months.
+pub trait CalendarSystem : Debug + Sync {
+    // Имя различает календари между собой: сравнение и порядок CalendarView::Custom идут по имени.
+    fn name(&self) -> &'static str;
+
+    fn is_leap_year(&self, year: u64) -> bool;
+
+    // Количество високосных лет с первого по указанный год включительно.
+    fn sum_leap_years(&self, year: u64) -> u64;
+
+    fn months_in_year(&self) -> u8 {
+        return MONTHS_IN_YEAR;
+    }
+
+    fn month_days(&self, month: u8, leap: bool) -> u8 {
+        return Months::from(month).days(leap);
+    }
+
+    fn year_days(&self, leap: bool) -> u16 {
+        let mut days: u16 = 0_u16;
+
+        for month in 1_u8..=self.months_in_year() {
+            days += self.month_days(month, leap) as u16;
+        }
+
+        return days;
+    }
+
+    // Сколько дней представления первого года находится до первого дня эры (у юлианского календаря два).
+    fn epoch_offset(&self) -> u128 {
+        return 0_u128;
+    }
+
+    // Сдвиг дня недели перед первым днём представления.
+    fn week_day_shift(&self) -> u8;
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct JulianCalendar;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GregorianCalendar;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SolarCalendar;
+
+// Структура года исторического календаря: дни представления юлианские, правило високосных лет зависит
+// от даты перехода и берётся из is_leap_year(CalendarView::Historical(..), year).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct HistoricalCalendar;
+
+impl CalendarSystem for JulianCalendar {
+    fn name(&self) -> &'static str {
+        return "Julian";
+    }
+
+    fn is_leap_year(&self, year: u64) -> bool {
+        return is_leap_year(CalendarView::Julian, year);
+    }
+
+    fn sum_leap_years(&self, year: u64) -> u64 {
+        return sum_leap_years(CalendarView::Julian, year);
+    }
+
+    fn year_days(&self, leap: bool) -> u16 {
+        return if leap { LEAP_DAYS_YEAR } else { BASE_DAYS_YEAR };
+    }
+
+    fn epoch_offset(&self) -> u128 {
+        return JULIAN_BCE_DAYS_FIRST_YEAR;
+    }
+
+    fn week_day_shift(&self) -> u8 {
+        return SHIFT_BEFORE_FIRST_PRESENTATION_WEEK_DAY_JULIAN;
+    }
+}
+
+impl CalendarSystem for GregorianCalendar {
+    fn name(&self) -> &'static str {
+        return "Gregorian";
+    }
+
+    fn is_leap_year(&self, year: u64) -> bool {
+        return is_leap_year(CalendarView::Gregorian, year);
+    }
+
+    fn sum_leap_years(&self, year: u64) -> u64 {
+        return sum_leap_years(CalendarView::Gregorian, year);
+    }
+
+    fn year_days(&self, leap: bool) -> u16 {
+        return if leap { LEAP_DAYS_YEAR } else { BASE_DAYS_YEAR };
+    }
+
+    fn week_day_shift(&self) -> u8 {
+        return SHIFT_BEFORE_FIRST_PRESENTATION_WEEK_DAY_GREGORIAN;
+    }
+}
+
+impl CalendarSystem for SolarCalendar {
+    fn name(&self) -> &'static str {
+        return "Solar";
+    }
+
+    fn is_leap_year(&self, year: u64) -> bool {
+        return is_leap_year(CalendarView::Solar, year);
+    }
+
+    fn sum_leap_years(&self, year: u64) -> u64 {
+        return sum_leap_years(CalendarView::Solar, year);
+    }
+
+    fn year_days(&self, leap: bool) -> u16 {
+        return if leap { LEAP_DAYS_YEAR } else { BASE_DAYS_YEAR };
+    }
+
+    fn week_day_shift(&self) -> u8 {
+        return SHIFT_BEFORE_FIRST_PRESENTATION_WEEK_DAY_SOLAR;
+    }
+}
+
+impl CalendarSystem for HistoricalCalendar {
+    fn name(&self) -> &'static str {
+        return "Historical";
+    }
+
+    fn is_leap_year(&self, year: u64) -> bool {
+        return is_leap_year(CalendarView::Julian, year);
+    }
+
+    fn sum_leap_years(&self, year: u64) -> u64 {
+        return sum_leap_years(CalendarView::Julian, year);
+    }
+
+    fn year_days(&self, leap: bool) -> u16 {
+        return if leap { LEAP_DAYS_YEAR } else { BASE_DAYS_YEAR };
+    }
+
+    fn epoch_offset(&self) -> u128 {
+        return JULIAN_BCE_DAYS_FIRST_YEAR;
+    }
+
+    fn week_day_shift(&self) -> u8 {
+        return SHIFT_BEFORE_FIRST_PRESENTATION_WEEK_DAY_JULIAN;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CalendarSystem, CalendarView
+    };
+
+    use crate::{
+        Date, Sign, Zone, MonthEnd,
+        RataDie, Sakamoto, Xavetar
+    };
+
+    // Новоюлианский календарь: вековые годы високосные, только если остаток от деления на 900 равен 200 или 600.
+    #[derive(Debug)]
+    struct RevisedJulian;
+
+    impl CalendarSystem for RevisedJulian {
+        fn name(&self) -> &'static str {
+            return "Revised Julian";
+        }
+
+        fn is_leap_year(&self, year: u64) -> bool {
+            return year % 4_u64 == 0_u64 && (year % 100_u64 != 0_u64 || year % 900_u64 == 200_u64 || year % 900_u64 == 600_u64);
+        }
+
+        fn sum_leap_years(&self, year: u64) -> u64 {
+            let centuries: u64 = year / 100_u64;
+
+            return year / 4_u64 - centuries + (centuries / 9_u64) * 2_u64 + (centuries % 9_u64 >= 2_u64) as u64 + (centuries % 9_u64 >= 6_u64) as u64;
+        }
+
+        fn week_day_shift(&self) -> u8 {
+            return 0_u8;
+        }
+    }
+
+    const REVISED_JULIAN: CalendarView = CalendarView::Custom(&RevisedJulian);
+
+    #[test]
+    fn test_custom_calendar_system() {
+        let time_zone: Zone = Zone { sign: Sign::Unsigned, hours: 2_u8, minutes: 0_u8, seconds: 0_u8 };
+
+        for year in 1_u64..=3000_u64 {
+            assert_eq!(REVISED_JULIAN.system().sum_leap_years(year), (1_u64..=year).filter(|year| RevisedJulian.is_leap_year(*year)).count() as u64);
+        }
+
+        let date: Date = Date::new(REVISED_JULIAN, 2024, 3, 1, time_zone).unwrap();
+        let gregorian: Date = Date::new(CalendarView::Gregorian, 2024, 3, 1, time_zone).unwrap();
+
+        assert_eq!(date, gregorian);
+        assert_eq!((date.era_days(), date.view().name()), (gregorian.era_days(), "Revised Julian"));
+        assert!(date.to_view(CalendarView::Gregorian).unwrap().same_civil_fields(&gregorian));
+        assert!(gregorian.to_view(REVISED_JULIAN).unwrap().same_civil_fields(&date));
+
+        let leap: Date = Date::new(CalendarView::Gregorian, 2800, 2, 29, time_zone).unwrap().to_view(REVISED_JULIAN).unwrap();
+        assert_eq!((leap.year(), leap.month().index(), leap.day()), (2800, 3, 1));
+        assert_eq!(Date::new(REVISED_JULIAN, 2800, 2, 29, time_zone), Err(crate::Error::InvalidDay(29)));
+
+        let week_day: u8 = <Date as RataDie>::week_day(&gregorian).index();
+        assert_eq!(<Date as RataDie>::week_day(&date).index(), week_day);
+        assert_eq!(<Date as Sakamoto>::week_day(&date).index(), week_day);
+        assert_eq!(<Date as Xavetar>::week_day(&date).index(), week_day);
+
+        let next_year: Date = date.add_years(1, MonthEnd::Clamp).unwrap();
+        assert_eq!((next_year.year(), next_year.month().index(), next_year.day(), next_year.view()), (2025, 3, 1, REVISED_JULIAN));
+        assert_eq!(next_year.checked_sub_days(365), Ok(date));
+
+        // До 1600 года новоюлианский календарь насчитывает на один високосный день больше григорианского.
+        let early: Date = Date::new(REVISED_JULIAN, 1500, 2, 29, time_zone).unwrap();
+        assert_eq!((early.era_days(), early.to_view(CalendarView::Gregorian).map(|date| (date.month().index(), date.day()))), (Date::new(CalendarView::Gregorian, 1500, 3, 1, time_zone).unwrap().era_days(), Ok((3, 1))));
+        assert_eq!(Date::new(REVISED_JULIAN, 1, 1, 1, time_zone).map(|date| date.era_days()), Ok(1));
+
+        assert_ne!(REVISED_JULIAN, CalendarView::Gregorian);
+        assert!(CalendarView::Solar < REVISED_JULIAN);
+    }
+}
@@ -29,6 +29,7 @@
 use super::{Converter};
 
 use crate::types::{
+    error::{Error},
     data::{
         date::{Date},
     },
@@ -36,77 +37,52 @@ use crate::types::{
         earth::{
             calendar::{
                 view::{CalendarView},
-                constants::{
-                    days::{JULIAN_BCE_DAYS_FIRST_YEAR}
-                },
-                functions::{
-                    days_from_presentation_date
-                },
             }
         }
     },
-    counter::{
-        unix_time::{
-            constants::{
-                days::{UNIX_TIME_START_AFTER_DAY}
-            },
-        }
-    }
 };
 
 pub trait Julian : Converter {
-    fn to_date(&mut self, zone_in_unix: bool);
-    fn to_presentation(&mut self, zone_in_unix: bool);
+    fn to_date(&mut self);
+    fn to_presentation(&mut self);
+    fn try_to_date(&mut self) -> Result<(), Error>;
+    fn try_to_presentation(&mut self) -> Result<(), Error>;
 }
 
 impl Julian for Date {
-    fn to_date(&mut self, zone_in_unix: bool) {
-        match self.view {
-            CalendarView::Julian => {
-                if self.year == 1_u64 {
-                    if self.month == 1_u8 {
-                        if (1_u8..=JULIAN_BCE_DAYS_FIRST_YEAR as u8).contains(&self.day) {
-                            panic!("[IMPOSSIBLE]: This presentation of days is missing in CE (Current Era) of Julian Calendar! (to_date)")
-                        }
-                    }
-                }
-
-                self.era_days = days_from_presentation_date(self.view.clone(), self.year, self.month, self.day);
-            },
-            CalendarView::Gregorian | CalendarView::Solar => {
-                self.era_days = days_from_presentation_date(self.view.clone(), self.year, self.month, self.day) + JULIAN_BCE_DAYS_FIRST_YEAR;
-            },
+    fn to_date(&mut self) {
+        if let Err(error) = <Date as Julian>::try_to_date(self) {
+            panic!("{} (to_date)", error)
         }
-
-        self.fill_time(UNIX_TIME_START_AFTER_DAY + JULIAN_BCE_DAYS_FIRST_YEAR, zone_in_unix);
-        self.fill_date(CalendarView::Julian);
-
-        self.era_days -= JULIAN_BCE_DAYS_FIRST_YEAR;
     }
 
-    fn to_presentation(&mut self, zone_in_unix: bool) {
-        if self.year == 1_u64 {
-            if self.month == 1_u8 {
-                if (1_u8..=JULIAN_BCE_DAYS_FIRST_YEAR as u8).contains(&self.day) {
-                    panic!("[IMPOSSIBLE]: This presentation of days is missing in CE (Current Era) of Julian Calendar! (to_date)")
-                }
-            }
+    fn to_presentation(&mut self) {
+        if let Err(error) = <Date as Julian>::try_to_presentation(self) {
+            panic!("{} (to_presentation)", error)
         }
+    }
 
-        self.era_days = days_from_presentation_date(CalendarView::Julian, self.year, self.month, self.day);
-
-        self.fill_time(UNIX_TIME_START_AFTER_DAY + JULIAN_BCE_DAYS_FIRST_YEAR, zone_in_unix);
-        self.fill_date(CalendarView::Julian);
+    fn try_to_date(&mut self) -> Result<(), Error> {
+        return self.convert_date(CalendarView::Julian);
+    }
 
-        self.era_days -= JULIAN_BCE_DAYS_FIRST_YEAR;
+    fn try_to_presentation(&mut self) -> Result<(), Error> {
+        return self.convert_presentation(CalendarView::Julian);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        CalendarView, Date,
-        JULIAN_BCE_DAYS_FIRST_YEAR
+        CalendarView, Date
+    };
+
+    use crate::types::{
+        planets::earth::calendar::{
+            constants::{
+                days::{JULIAN_BCE_DAYS_FIRST_YEAR}
+            },
+        }
     };
 
     use crate::{
@@ -117,16 +93,6 @@ mod tests {
         },
     };
 
-    use crate::types::{
-        counter::{
-            unix_time::{
-                constants::{
-                    year::{UNIX_EPOCH_JULIAN_START_YEAR}
-                }
-            }
-        },
-    };
-
     #[test]
     fn test_between_date_conversion_to_julian() {
         let mut date: Date = Date::default();
@@ -134,10 +100,10 @@ mod tests {
         let max_year_to_test: u64 = 2_500_u64;
 
         for (day_seconds, time_zone) in [
-            (83599_u128, Zone { sign: Sign::Signed, hours: 5_u8, minutes: 30_u8, seconds: 0_u8 }),
-            (55_u128, Zone { sign: Sign::Signed, hours: 23_u8, minutes: 59_u8, seconds: 59_u8 }),
-            (45_u128, Zone { sign: Sign::Unsigned, hours: 9_u8, minutes: 30_u8, seconds: 0_u8 }),
-            (55_u128, Zone { sign: Sign::Unsigned, hours: 23_u8, minutes: 59_u8, seconds: 59_u8 })
+            (83599_i128, Zone { sign: Sign::Signed, hours: 5_u8, minutes: 30_u8, seconds: 0_u8 }),
+            (55_i128, Zone { sign: Sign::Signed, hours: 23_u8, minutes: 59_u8, seconds: 59_u8 }),
+            (45_i128, Zone { sign: Sign::Unsigned, hours: 9_u8, minutes: 30_u8, seconds: 0_u8 }),
+            (55_i128, Zone { sign: Sign::Unsigned, hours: 23_u8, minutes: 59_u8, seconds: 59_u8 })
         ] {
             for year in 1_u64..=max_year_to_test {
                 for month in [
@@ -146,43 +112,23 @@ mod tests {
                 ] {
                     for day in 1_u8..=month.days(is_leap_year(CalendarView::Julian, year)) {
                         if !(1_u128..=JULIAN_BCE_DAYS_FIRST_YEAR).contains(&(day as u128)) || month.index() != Months::January.index() || year != 1 {
-                            if time_zone.sign == Sign::Signed {
-                                let tz_seconds: u128 = time_zone.to_seconds() as u128;
-
-                                (date.day, date.month, date.year, date.unix_time, date.view) = (day, month.index(), year, day_seconds, CalendarView::Julian);
-                                <Date as Julian>::to_date(&mut date, true);
-
-                                if month.index() == Months::December.index() {
-                                    if year == UNIX_EPOCH_JULIAN_START_YEAR as u64 {
-                                        if date.unix_time + day_seconds < tz_seconds {
-                                            continue;
-                                        }
-                                    }
-                                }
-                            } else if time_zone.sign == Sign::Unsigned {
-                                let tz_seconds: u128 = time_zone.to_seconds() as u128;
-
-                                (date.day, date.month, date.year, date.unix_time, date.view) = (day, month.index(), year, day_seconds, CalendarView::Julian);
-                                <Date as Julian>::to_date(&mut date, true);
-
-                                if date.unix_time > u128::MAX - tz_seconds {
-                                    continue;
-                                }
-                            }
-
                             (date.day, date.month, date.year, date.time_zone, date.unix_time, date.view) = (day, month.index(), year, time_zone, day_seconds, CalendarView::Julian);
-                            <Date as Julian>::to_date(&mut date, false);
 
-                            let (day_tz, month_tz, year_tz, unix_time): (u8, u8, u64, u128) = (date.day, date.month, date.year, date.unix_time);
+                            <Date as Julian>::to_date(&mut date);
+
+                            // Момент UTC, полученный из местного времени, возвращает ту же дату в том же часовом поясе.
+                            assert_eq!(Date::try_from(CalendarView::Julian, date.instant(), time_zone), Ok(date));
+
+                            let (day_tz, month_tz, year_tz, unix_time): (u8, u8, u64, i128) = (date.day, date.month, date.year, date.unix_time);
 
-                            <Date as Solar>::to_date(&mut date, true);
+                            <Date as Solar>::to_date(&mut date);
 
-                            <Date as Julian>::to_date(&mut date, true);
+                            <Date as Julian>::to_date(&mut date);
                             assert_eq!((date.day, date.month, date.year, date.time_zone, date.unix_time, date.view), (day_tz, month_tz, year_tz, time_zone, unix_time, CalendarView::Julian));
 
-                            <Date as Gregorian>::to_date(&mut date, true);
+                            <Date as Gregorian>::to_date(&mut date);
 
-                            <Date as Julian>::to_date(&mut date, true);
+                            <Date as Julian>::to_date(&mut date);
                             assert_eq!((date.day, date.month, date.year, date.time_zone, date.unix_time, date.view), (day_tz, month_tz, year_tz, time_zone, unix_time, CalendarView::Julian));
                         }
                     }
@@ -206,22 +152,22 @@ mod tests {
                     if !(1_u128..=JULIAN_BCE_DAYS_FIRST_YEAR).contains(&(day as u128)) || month.index() != Months::January.index() || year != 1 {
 
                         (date.day, date.month, date.year, date.view) = (day, month.index(), year, CalendarView::Julian);
-                        <Date as Julian>::to_presentation(&mut date, false);
+                        <Date as Julian>::to_presentation(&mut date);
                         assert_eq!((date.day, date.month, date.year, date.view), (day, month.index(), year, CalendarView::Julian));
 
-                        let unix_time: u128 = date.unix_time;
+                        let unix_time: i128 = date.unix_time;
 
                         if day != 29 && month.index() != Months::February.index() {
-                            <Date as Solar>::to_presentation(&mut date, true);
+                            <Date as Solar>::to_presentation(&mut date);
                             assert_eq!((date.day, date.month, date.year, date.view), (day, month.index(), year, CalendarView::Solar));
 
-                            <Date as Julian>::to_presentation(&mut date, true);
+                            <Date as Julian>::to_presentation(&mut date);
                             assert_eq!((date.day, date.month, date.year, date.unix_time, date.view), (day, month.index(), year, unix_time, CalendarView::Julian));
 
-                            <Date as Gregorian>::to_presentation(&mut date, true);
+                            <Date as Gregorian>::to_presentation(&mut date);
                             assert_eq!((date.day, date.month, date.year, date.view), (day, month.index(), year, CalendarView::Gregorian));
 
-                            <Date as Julian>::to_presentation(&mut date, true);
+                            <Date as Julian>::to_presentation(&mut date);
                             assert_eq!((date.day, date.month, date.year, date.unix_time, date.view), (day, month.index(), year, unix_time, CalendarView::Julian));
                         }
                     }
@@ -26,14 +26,12 @@
  * THE SOFTWARE.
  */
 
-mod tz;
 mod solar;
 mod julian;
 mod gregorian;
 mod unification;
 
-pub(self) use tz::{zone_re_calc};
-pub(self) use unification::{Converter};
+pub(crate) use unification::{Converter};
 
 pub use solar::{Solar};
 pub use julian::{Julian};
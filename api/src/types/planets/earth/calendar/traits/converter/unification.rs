@@ -26,9 +26,8 @@
  * THE SOFTWARE.
  */
 
-use super::{zone_re_calc};
-
 use crate::types::{
+    error::{Error},
     data::{
         date::{Date},
     },
@@ -39,11 +38,17 @@ use crate::types::{
                 constants::{
                     seconds::{SECONDS_IN_DAY},
                 },
+                functions::{
+                    days_from_presentation_date, validate_presentation_date, last_presentation_day
+                },
             }
         }
     },
     counter::{
         unix_time::{
+            constants::{
+                days::{UNIX_TIME_START_AFTER_DAY}
+            },
             functions::{
                 year_from_presentation_days, month_from_days,
             }
@@ -52,36 +57,90 @@ use crate::types::{
 };
 
 pub trait Converter {
-    fn fill_date(&mut self, to: CalendarView);
-    fn fill_time(&mut self, day_before_timer_start: u128, zone_in_unix: bool);
+    fn fill_date(&mut self, to: CalendarView) -> Result<(), Error>;
+    fn fill_time(&mut self, day_before_timer_start: u128) -> Result<(), Error>;
+    fn convert_date(&mut self, to: CalendarView) -> Result<(), Error>;
+    fn convert_presentation(&mut self, to: CalendarView) -> Result<(), Error>;
 }
 
 impl Converter for Date {
-    fn fill_date(&mut self, to: CalendarView) {
+    fn fill_date(&mut self, to: CalendarView) -> Result<(), Error> {
         let mut days: u16;
 
+        if self.era_days > last_presentation_day(to) {
+            return Err(Error::Overflow);
+        }
+
         (self.year, days) = year_from_presentation_days(to, self.era_days);
 
         self.month = month_from_days(to, self.year, &mut days).index();
 
         (self.day, self.view) = (days as u8, to);
+
+        return Ok(());
     }
 
-    fn fill_time(&mut self, day_before_timer_start: u128, zone_in_unix: bool) {
-        if self.era_days > day_before_timer_start {
-            let day_seconds: u128 = self.unix_time % SECONDS_IN_DAY;
+    fn fill_time(&mut self, day_before_timer_start: u128) -> Result<(), Error> {
+        // Секунды внутри дня сохраняются, дата уже находится в местном времени часового пояса.
+        let day_seconds: i128 = self.unix_time.rem_euclid(SECONDS_IN_DAY as i128);
 
-            self.unix_time = (self.era_days - (day_before_timer_start + 1)) * SECONDS_IN_DAY;
+        if self.era_days <= day_before_timer_start - UNIX_TIME_START_AFTER_DAY {
+            return Err(Error::OutsideEra(self.view));
+        }
 
-            // Используется в случае когда временная зона не находится в unix time, позволяет указать время внутри дня,
-            // с учётом секунд внутри дня ± часовой пояс.
-            if !zone_in_unix {
-                zone_re_calc(self.time_zone, &mut self.unix_time, day_seconds, &mut self.era_days);
-            } else {
-                self.unix_time += day_seconds;
-            }
+        // До 1970 года unix time отрицательный.
+        self.unix_time = i128::try_from(self.era_days)
+            .ok()
+            .and_then(|era_days| era_days.checked_sub((day_before_timer_start + 1_u128) as i128))
+            .and_then(|unix_days| unix_days.checked_mul(SECONDS_IN_DAY as i128))
+            .and_then(|unix_time| unix_time.checked_add(day_seconds))
+            .ok_or(Error::Overflow)?;
+
+        return Ok(());
+    }
+
+    fn convert_date(&mut self, to: CalendarView) -> Result<(), Error> {
+        let mut date: Date = *self;
+
+        validate_presentation_date(date.view, date.year, date.month, date.day)?;
+
+        let (from_offset, to_offset): (u128, u128) = (date.view.system().epoch_offset(), to.system().epoch_offset());
+
+        // Дни представления исходного календаря переводятся в дни эры, а затем в дни представления целевого.
+        date.era_days = days_from_presentation_date(date.view, date.year, date.month, date.day);
+
+        if date.era_days > from_offset {
+            date.era_days = (date.era_days - from_offset).checked_add(to_offset).ok_or(Error::Overflow)?;
         } else {
-            self.unix_time = 0_u128;
+            return Err(Error::OutsideEra(to));
         }
+
+        date.fill_time(UNIX_TIME_START_AFTER_DAY + to_offset)?;
+        date.fill_date(to)?;
+
+        date.era_days -= to_offset;
+
+        *self = date;
+
+        return Ok(());
+    }
+
+    fn convert_presentation(&mut self, to: CalendarView) -> Result<(), Error> {
+        let mut date: Date = *self;
+
+        validate_presentation_date(to, date.year, date.month, date.day)?;
+
+        let offset: u128 = to.system().epoch_offset();
+
+        date.era_days = days_from_presentation_date(to, date.year, date.month, date.day);
+
+        date.fill_time(UNIX_TIME_START_AFTER_DAY + offset)?;
+        date.fill_date(to)?;
+
+        date.era_days -= offset;
+
+        *self = date;
+
+        return Ok(());
     }
-}
\ No newline at end of file
+}
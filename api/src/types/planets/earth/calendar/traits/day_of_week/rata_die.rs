@@ -56,7 +56,7 @@ pub trait RataDie {
 
 impl RataDie for Date {
     fn week_day(&self) -> Week {
-        return <Date as RataDie>::from(self.view.clone(), self.year, self.month, self.day);
+        return <Date as RataDie>::from(self.view, self.year, self.month, self.day);
     }
 
     fn from(view: CalendarView, year: u64, month: u8, day: u8) -> Week {
@@ -64,6 +64,7 @@ impl RataDie for Date {
             CalendarView::Solar => SHIFT_BEFORE_FIRST_PRESENTATION_WEEK_DAY_SOLAR as u128,
             CalendarView::Julian => SHIFT_BEFORE_FIRST_PRESENTATION_WEEK_DAY_JULIAN as u128,
             CalendarView::Gregorian => SHIFT_BEFORE_FIRST_PRESENTATION_WEEK_DAY_GREGORIAN as u128,
+            CalendarView::Custom(_) | CalendarView::Historical(_) => view.system().week_day_shift() as u128,
         };
 
         let era_days: u128 = days_from_presentation_date(view, year, month, day);
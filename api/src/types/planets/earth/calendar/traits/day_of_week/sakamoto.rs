@@ -49,6 +49,9 @@ use crate::types::{
                 functions::{
                     sum_leap_years
                 },
+                traits::{
+                    day_of_week::{RataDie}
+                },
             }
         }
     }
@@ -61,7 +64,7 @@ pub trait Sakamoto {
 
 impl Sakamoto for Date {
     fn week_day(&self) -> Week {
-        return <Date as Sakamoto>::from(self.view.clone(), self.year, self.month, self.day);
+        return <Date as Sakamoto>::from(self.view, self.year, self.month, self.day);
     }
 
     fn from(view: CalendarView, year: u64, month: u8, day: u8) -> Week {
@@ -69,6 +72,8 @@ impl Sakamoto for Date {
             CalendarView::Solar => &SOLAR_BASE_SAKAMOTO,
             CalendarView::Julian => &JULIAN_BASE_SAKAMOTO,
             CalendarView::Gregorian => &GREGORIAN_BASE_SAKAMOTO,
+            // Таблиц сдвигов для пользовательского и исторического календарей нет, день недели считается по дням представления.
+            CalendarView::Custom(_) | CalendarView::Historical(_) => return <Date as RataDie>::from(view, year, month, day),
         };
 
         let mut local_year: u64 = year;
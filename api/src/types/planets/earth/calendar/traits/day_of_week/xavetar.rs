@@ -49,6 +49,9 @@ use crate::types::{
                 functions::{
                     is_leap_year, sum_leap_years,
                 },
+                traits::{
+                    day_of_week::{RataDie}
+                },
             }
         }
     }
@@ -61,7 +64,7 @@ pub trait Xavetar {
 
 impl Xavetar for Date {
     fn week_day(&self) -> Week {
-        return <Date as Xavetar>::from(self.view.clone(), self.year, self.month, self.day);
+        return <Date as Xavetar>::from(self.view, self.year, self.month, self.day);
     }
 
     fn from(view: CalendarView, year: u64, month: u8, day: u8) -> Week {
@@ -69,6 +72,8 @@ impl Xavetar for Date {
             CalendarView::Solar => (&SOLAR_BASE_XAVETAR, &SOLAR_LEAP_XAVETAR),
             CalendarView::Julian => (&JULIAN_BASE_XAVETAR, &JULIAN_LEAP_XAVETAR),
             CalendarView::Gregorian => (&GREGORIAN_BASE_XAVETAR, &GREGORIAN_LEAP_XAVETAR),
+            // Таблиц сдвигов для пользовательского и исторического календарей нет, день недели считается по дням представления.
+            CalendarView::Custom(_) | CalendarView::Historical(_) => return <Date as RataDie>::from(view, year, month, day),
         };
 
         let last_year: u64 = year - 1_u64;
@@ -26,10 +26,76 @@
  * THE SOFTWARE.
  */
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+use std::{
+    cmp::{Ordering},
+    hash::{Hash, Hasher}
+};
+
+use super::{
+    cutover::{Cutover},
+    system::{CalendarSystem, JulianCalendar, GregorianCalendar, SolarCalendar, HistoricalCalendar}
+};
+
+#[derive(Default, Debug, Clone, Copy)]
 pub enum CalendarView {
     #[default]
-    Julian = 0,
-    Gregorian = 1,
-    Solar = 2
-}
\ No newline at end of file
+    Julian,
+    Gregorian,
+    Solar,
+    Custom(&'static dyn CalendarSystem),
+    // До даты перехода юлианский календарь, начиная с неё григорианский.
+    Historical(Cutover)
+}
+
+impl CalendarView {
+    pub fn system(&self) -> &'static dyn CalendarSystem {
+        match self {
+            CalendarView::Julian => return &JulianCalendar,
+            CalendarView::Gregorian => return &GregorianCalendar,
+            CalendarView::Solar => return &SolarCalendar,
+            CalendarView::Custom(system) => return *system,
+            CalendarView::Historical(_) => return &HistoricalCalendar,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        return self.system().name();
+    }
+
+    // Встроенные календари сравниваются по варианту, пользовательские по имени системы, исторические по дате перехода.
+    fn key(&self) -> (u8, &'static str, Option<Cutover>) {
+        match self {
+            CalendarView::Julian => return (0_u8, "", None),
+            CalendarView::Gregorian => return (1_u8, "", None),
+            CalendarView::Solar => return (2_u8, "", None),
+            CalendarView::Custom(system) => return (3_u8, system.name(), None),
+            CalendarView::Historical(cutover) => return (4_u8, "", Some(*cutover)),
+        }
+    }
+}
+
+impl PartialEq for CalendarView {
+    fn eq(&self, other: &CalendarView) -> bool {
+        return self.key() == other.key();
+    }
+}
+
+impl Eq for CalendarView {}
+
+impl PartialOrd for CalendarView {
+    fn partial_cmp(&self, other: &CalendarView) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for CalendarView {
+    fn cmp(&self, other: &CalendarView) -> Ordering {
+        return self.key().cmp(&other.key());
+    }
+}
+
+impl Hash for CalendarView {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
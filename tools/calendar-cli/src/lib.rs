@@ -29,6 +29,11 @@
 #![allow(non_snake_case)]
 #![allow(non_camel_case_types)]
 
+#![allow(clippy::needless_return)]
+#![allow(clippy::iter_nth_zero)]
+#![allow(clippy::if_same_then_else)]
+#![allow(clippy::too_many_arguments)]
+
 pub mod text;
 pub mod save;
 pub mod types;
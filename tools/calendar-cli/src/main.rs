@@ -26,6 +26,10 @@
  * THE SOFTWARE.
  */
 
+#![allow(clippy::needless_return)]
+#![allow(clippy::needless_late_init)]
+#![allow(clippy::type_complexity)]
+
 use PHASEXave::{
     CalendarView, Date, Week,
     RataDie,
@@ -45,13 +49,14 @@ use calendar_cli::{
     }
 };
 
-fn make_calendar(view: CalendarView, method: fn(CalendarView, u64, u8, u8) -> Week, year: u64, columns: u8, margin: [u8; 4]) -> Vec<Vec<char>> {
+fn make_calendar(view: CalendarView, method: fn(CalendarView, u64, u8, u8) -> Week, year: u64, columns: u8, margin: [u8; 4], ordinal: bool) -> Vec<Vec<char>> {
     return format_calendar_from_text_months(
-        year, columns, margin,
+        year, columns, margin, ordinal,
         format_months_to_text(
             &format_months_by_days(
-                view, method, year, is_leap_year(view, year)
-            )
+                view, method, year, is_leap_year(view, year), ordinal
+            ),
+            ordinal
         )
     );
 }
@@ -63,6 +68,7 @@ fn main() {
         mut columns,
         mut margin,
         mut view,
+        mut ordinal,
         mut mode,
         mut filename
     )
@@ -73,23 +79,25 @@ fn main() {
         u8,
         [u8; 4],
         CalendarView,
+        bool,
         Modes,
         String
     )
     =
     (
-        Date::local(CalendarView::Gregorian).year,
+        Date::local(CalendarView::Gregorian).year(),
         <Date as RataDie>::from,
         3,
         [0, 1, 1, 1],
         CalendarView::Gregorian,
+        false,
         Modes::Console,
         String::from("Calendar.txt")
     );
     
-    parse_args(&mut year, &mut method, &mut columns, &mut margin, &mut view, &mut mode, &mut filename);
+    parse_args(&mut year, &mut method, &mut columns, &mut margin, &mut view, &mut ordinal, &mut mode, &mut filename);
 
-    let calendar_text: Vec<Vec<char>> = make_calendar(view, method, year, columns, margin);
+    let calendar_text: Vec<Vec<char>> = make_calendar(view, method, year, columns, margin, ordinal);
 
     if mode == Modes::File {
         save_to_file(filename, calendar_text);
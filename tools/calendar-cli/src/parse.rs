@@ -61,6 +61,8 @@ fn print_help() {
                                                               2 - Gregorian (default)
                                                               3 - Solar
 
+        -j, --ordinal                  Show days of year (1-366) instead of days of month
+
         -f, --filename [FILENAME]      Save to filename: Calendar.txt (default)
 
         --mode [MODE]                  Set the mode: 1 - To file
@@ -73,7 +75,7 @@ fn print_help() {
     );
 }
 
-pub fn parse_args(year: &mut u64, method: &mut fn(CalendarView, u64, u8, u8) -> Week, columns: &mut u8, margin: &mut [u8; 4], view: &mut CalendarView, mode: &mut Modes, filename: &mut String) {
+pub fn parse_args(year: &mut u64, method: &mut fn(CalendarView, u64, u8, u8) -> Week, columns: &mut u8, margin: &mut [u8; 4], view: &mut CalendarView, ordinal: &mut bool, mode: &mut Modes, filename: &mut String) {
     let mut args: std::iter::Skip<std::env::Args> = std::env::args().skip(1);
 
     while let Some(arg) = args.next() {
@@ -177,17 +179,15 @@ pub fn parse_args(year: &mut u64, method: &mut fn(CalendarView, u64, u8, u8) ->
                     std::process::exit(0);
                 }
             }
+            "-j" | "--ordinal" => {
+                *ordinal = true;
+            }
             "-f" | "--filename" => {
                 if let Some(filename_str) = args.next() {
-                    if let Ok(parsed_filename) = filename_str.parse::<String>() {
-                        if parsed_filename.len() >= 1 {
-                            *filename = parsed_filename;
-                        } else {
-                            println!("[ERROR]: Length cannot be less than 1 character");
-                        }
+                    if !filename_str.is_empty() {
+                        *filename = filename_str;
                     } else {
-                        println!("[ERROR]: Value is not a String: --save [FILENAME]");
-                        std::process::exit(0);
+                        println!("[ERROR]: Length cannot be less than 1 character");
                     }
                 } else {
                     println!("[ERROR]: Value not provided: --save");
@@ -27,7 +27,7 @@
  */
 
 use PHASEXave::{
-    CalendarView, Months, Week,
+    CalendarView, Date, Months, Week, Zone,
     constants::{DAYS_IN_WEEK, MONTHS_IN_YEAR},
 };
 
@@ -42,13 +42,14 @@ const ROWS_MATRIX_MONTH: u8 = (MAX_SHIFT_DAYS_IN_WEEK + MAX_DAYS_IN_MONTH + (DAY
 const DAYS_IN_MONTH_LINEAR: u8 = COLUMNS_MATRIX_MONTH * ROWS_MATRIX_MONTH;
 
 const DAY_WIDTH: u8 = 2;
-const DAY_FIELD_WIDTH: u8 = DAY_WIDTH + 1;
+const ORDINAL_WIDTH: u8 = 3; // day of year: 1-366
 
-const LINE_WIDTH: u8 = DAY_FIELD_WIDTH * DAYS_IN_WEEK;
+// Text arrays fit the widest (ordinal) line, narrow lines use only the beginning
+const LINE_WIDTH: u8 = (ORDINAL_WIDTH + 1) * DAYS_IN_WEEK;
 const LINE_HEIGTH: u8 = ROWS_MATRIX_MONTH + 1 + 1 + 1; // rows + week names + space + header
 
 // 2D Array
-pub type DAYS_IN_MONTHS_LINEAR = [[u8; DAYS_IN_MONTH_LINEAR as usize]; MONTHS_IN_YEAR as usize];
+pub type DAYS_IN_MONTHS_LINEAR = [[u16; DAYS_IN_MONTH_LINEAR as usize]; MONTHS_IN_YEAR as usize];
 
 // 2D Array
 pub type MONTH_TEXT = [[char; LINE_WIDTH as usize]; (LINE_HEIGTH) as usize];
@@ -69,13 +70,27 @@ fn to_chars<T: Default + Copy + std::ops::IndexMut<usize, Output = char>>(cursor
     return line;
 }
 
-pub fn format_calendar_from_text_months(year: u64, columns: u8, margin: [u8; 4], text_by_months_in_year: TEXT_BY_MONTHS_IN_YEAR) -> Vec<Vec<char>> {
+const fn day_width(ordinal: bool) -> u8 {
+    if ordinal {
+        return ORDINAL_WIDTH;
+    } else {
+        return DAY_WIDTH;
+    }
+}
+
+const fn line_width(ordinal: bool) -> u8 {
+    return (day_width(ordinal) + 1) * DAYS_IN_WEEK;
+}
+
+pub fn format_calendar_from_text_months(year: u64, columns: u8, margin: [u8; 4], ordinal: bool, text_by_months_in_year: TEXT_BY_MONTHS_IN_YEAR) -> Vec<Vec<char>> {
     let mut cursor_line: usize = 0;
 
+    let line_width: u8 = line_width(ordinal);
+
     // Equivalent to: MONTHS_IN_YEAR.div_ceil(columns)
     let row_objects: u8 = (MONTHS_IN_YEAR + (columns - 1)) / columns;
 
-    let width_calendar: u16 = (LINE_WIDTH as u16 * columns as u16) + ((margin[1] as u16 + margin[3] as u16) * (columns as u16));
+    let width_calendar: u16 = (line_width as u16 * columns as u16) + ((margin[1] as u16 + margin[3] as u16) * (columns as u16));
     let height_calendar: u16 = (LINE_HEIGTH as u16 * row_objects as u16) + ((margin[0] as u16 + margin[2] as u16) * (row_objects as u16)) + 1 + 1; // calendar height + space + year (header)
 
     let mut calendar_text: Vec<Vec<char>> = Vec::<Vec<char>>::with_capacity(height_calendar as usize);
@@ -101,7 +116,7 @@ pub fn format_calendar_from_text_months(year: u64, columns: u8, margin: [u8; 4],
                 for column in 0..columns {
                     if (month_index + column) < MONTHS_IN_YEAR {
                         calendar_text[cursor_line].append(format!("{:^width$}", "", width = margin[3] as usize).chars().collect::<Vec<char>>().as_mut());
-                        calendar_text[cursor_line].append(text_by_months_in_year[(month_index + column) as usize][line as usize].into_iter().collect::<Vec<char>>().as_mut());
+                        calendar_text[cursor_line].append(text_by_months_in_year[(month_index + column) as usize][line as usize][..line_width as usize].to_vec().as_mut());
                         calendar_text[cursor_line].append(format!("{:^width$}", "", width = margin[1] as usize).chars().collect::<Vec<char>>().as_mut());
                     }
                 }
@@ -119,7 +134,14 @@ pub fn format_calendar_from_text_months(year: u64, columns: u8, margin: [u8; 4],
     return calendar_text;
 }
 
-pub fn format_months_to_text(calendar: &DAYS_IN_MONTHS_LINEAR) -> TEXT_BY_MONTHS_IN_YEAR {
+pub fn format_months_to_text(calendar: &DAYS_IN_MONTHS_LINEAR, ordinal: bool) -> TEXT_BY_MONTHS_IN_YEAR {
+    let (day_width, line_width): (usize, usize) = (day_width(ordinal) as usize, line_width(ordinal) as usize);
+
+    let week_names: String = (1..=DAYS_IN_WEEK)
+        .map(|week_day| format!("{:>width$}", &Week::from(week_day).name()[..2], width = day_width))
+        .collect::<Vec<String>>()
+        .join(" ");
+
     let mut text_by_months_in_year: TEXT_BY_MONTHS_IN_YEAR =  [[['0'; LINE_WIDTH as usize]; (LINE_HEIGTH) as usize]; MONTHS_IN_YEAR as usize];
 
     for (month, matrix) in calendar.iter().enumerate() {
@@ -127,23 +149,23 @@ pub fn format_months_to_text(calendar: &DAYS_IN_MONTHS_LINEAR) -> TEXT_BY_MONTHS
 
         let mut month_text: MONTH_TEXT = [['0'; LINE_WIDTH as usize]; (LINE_HEIGTH) as usize];
 
-        month_text[cursor_line - 1] = to_chars::<[char; LINE_WIDTH as usize]>(&mut cursor_line, format!("{:^width$}", Months::from((month + 1) as u8).name(), width = LINE_WIDTH as usize));
+        month_text[cursor_line - 1] = to_chars::<[char; LINE_WIDTH as usize]>(&mut cursor_line, format!("{:^width$}", Months::from((month + 1) as u8).name(), width = line_width));
 
-        month_text[cursor_line - 1] = to_chars::<[char; LINE_WIDTH as usize]>(&mut cursor_line, format!("{:^width$}", "", width = LINE_WIDTH as usize));
+        month_text[cursor_line - 1] = to_chars::<[char; LINE_WIDTH as usize]>(&mut cursor_line, format!("{:^width$}", "", width = line_width));
 
-        month_text[cursor_line - 1] = to_chars::<[char; LINE_WIDTH as usize]>(&mut cursor_line, format!("{:^width$}", "Mo Tu We Th Fr Sa Su", width = LINE_WIDTH as usize));
+        month_text[cursor_line - 1] = to_chars::<[char; LINE_WIDTH as usize]>(&mut cursor_line, format!("{:^width$}", week_names, width = line_width));
 
         let mut line: String = String::from("");
 
         for (i, field) in matrix.iter().enumerate() {
             if *field == 0 {
-                line.push_str("   ");
+                line.push_str(&format!("{:width$}", "", width = day_width + 1));
             } else {
-                line.push_str(&format!("{:2} ", field));
+                line.push_str(&format!("{:>width$} ", field, width = day_width));
             }
 
-            if ((i + 1) as u8 % DAYS_IN_WEEK) == 0 && (line.len() as u8) == LINE_WIDTH {
-                month_text[cursor_line - 1] = to_chars::<[char; LINE_WIDTH as usize]>(&mut cursor_line, format!("{:^21}", line));
+            if ((i + 1) as u8 % DAYS_IN_WEEK) == 0 && line.len() == line_width {
+                month_text[cursor_line - 1] = to_chars::<[char; LINE_WIDTH as usize]>(&mut cursor_line, format!("{:^width$}", line, width = line_width));
                 line.clear();
             }
         }
@@ -154,15 +176,21 @@ pub fn format_months_to_text(calendar: &DAYS_IN_MONTHS_LINEAR) -> TEXT_BY_MONTHS
     return text_by_months_in_year;
 }
 
-pub fn format_months_by_days(view: CalendarView, method: fn(CalendarView, u64, u8, u8) -> Week, year: u64, is_leap_year: bool) -> DAYS_IN_MONTHS_LINEAR {
+pub fn format_months_by_days(view: CalendarView, method: fn(CalendarView, u64, u8, u8) -> Week, year: u64, is_leap_year: bool, ordinal: bool) -> DAYS_IN_MONTHS_LINEAR {
     // 2D Array
     let mut days_in_months: DAYS_IN_MONTHS_LINEAR = [[0; DAYS_IN_MONTH_LINEAR as usize]; MONTHS_IN_YEAR as usize];
 
-    for month_in_year in 1..(MONTHS_IN_YEAR + 1) {
+    for month_in_year in 1..(view.system().months_in_year() + 1) {
         let month: Months = Months::from(month_in_year);
         let shift: u8 = method(view, year, month.index(), 1).index() - 1;
-        for day_in_month in 1..(month.days(is_leap_year) + 1) {
-            days_in_months[(month_in_year - 1) as usize][(shift + (day_in_month - 1)) as usize] = day_in_month;
+        for day_in_month in 1..(view.system().month_days(month.index(), is_leap_year) + 1) {
+            days_in_months[(month_in_year - 1) as usize][(shift + (day_in_month - 1)) as usize] = if ordinal {
+                Date::new(view, year, month.index(), day_in_month, Zone::default())
+                    .expect("[ERROR]: Date is out of range (format_months_by_days)!")
+                    .ordinal()
+            } else {
+                day_in_month as u16
+            };
         }
 
     }
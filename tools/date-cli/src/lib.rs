@@ -29,4 +29,8 @@
 #![allow(non_snake_case)]
 #![allow(non_camel_case_types)]
 
+#![allow(clippy::needless_return)]
+#![allow(clippy::iter_nth_zero)]
+#![allow(clippy::if_same_then_else)]
+
 pub mod parse;
\ No newline at end of file
@@ -26,9 +26,13 @@
  * THE SOFTWARE.
  */
 
+#![allow(clippy::needless_return)]
+#![allow(clippy::needless_late_init)]
+#![allow(clippy::type_complexity)]
+
 use PHASEXave::{
     CalendarView,
-    Date, Time,
+    Date, DateTime,
     Zone, Sign,
     Months, Week,
     RataDie,
@@ -39,29 +43,31 @@ use date_cli::{
 };
 
 fn make_output(view: CalendarView, time_zone: Zone, method: fn(CalendarView, u64, u8, u8) -> Week) {
-    let date: Date;
+    let date_time: DateTime;
 
     if time_zone.sign == Sign::Unsigned && time_zone.hours == 255 && time_zone.minutes == 255 && time_zone.seconds == 255 {
-        date = Date::local(view);
+        date_time = DateTime::local(view);
     } else {
-        date = Date::now(view, time_zone);
+        date_time = DateTime::now(view, time_zone);
     }
 
-    let time: Time = Time::now(date.time_zone);
+    let month: Months = date_time.month();
 
     print!(
         "{day_of_week} {month} {day} {hours:02}:{minutes:02}:{seconds:02}",
-        day_of_week = method(view, date.year, date.month, date.day).name(),
-        month = Months::from(date.month).name(),
-        day = date.day,
-        hours = time.hours,
-        minutes = time.minutes,
-        seconds = time.seconds
+        day_of_week = method(view, date_time.year(), month.index(), date_time.day()).name(),
+        month = month.name(),
+        day = date_time.day(),
+        hours = date_time.hours(),
+        minutes = date_time.minutes(),
+        seconds = date_time.seconds()
     );
 
-    if date.time_zone.sign == Sign::Unsigned {
+    let time_zone: Zone = date_time.time_zone();
+
+    if time_zone.sign == Sign::Unsigned {
         print!("+")
-    } else if date.time_zone.sign == Sign::Signed {
+    } else if time_zone.sign == Sign::Signed {
         print!("-");
     } else {
         panic!("[PANIC] Unknown zone sign!");
@@ -69,11 +75,11 @@ fn make_output(view: CalendarView, time_zone: Zone, method: fn(CalendarView, u64
 
     println!(
         "{timezone_hours:02}:{timezone_minutes:02}:{timezone_seconds:02} {year} {ce_era_days} {calendar_view:?}",
-        timezone_hours = date.time_zone.hours,
-        timezone_minutes = date.time_zone.minutes,
-        timezone_seconds = date.time_zone.seconds,
-        year = date.year,
-        ce_era_days = date.era_days,
+        timezone_hours = time_zone.hours,
+        timezone_minutes = time_zone.minutes,
+        timezone_seconds = time_zone.seconds,
+        year = date_time.year(),
+        ce_era_days = date_time.era_days(),
         calendar_view = view
     )
 }